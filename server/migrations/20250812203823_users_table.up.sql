@@ -1,9 +1,9 @@
--- Add migration script here
-DROP TABLE IF EXISTS users;
-CREATE TABLE users(
+-- Never drop the table first: running this against a database that already
+-- has users must leave them alone.
+CREATE TABLE IF NOT EXISTS users(
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
     username VARCHAR(60) NOT NULL,
     email VARCHAR(255) NOT NULL,
     password_hash VARCHAR(255) NOT NULL,
     created_at TIMESTAMP default CURRENT_TIMESTAMP
-);
\ No newline at end of file
+);
@@ -1,8 +1,8 @@
--- Add migration script here
-DROP TABLE IF EXISTS avatars;
-CREATE TABLE avatars (
+-- Never drop the table first: running this against a database that already
+-- has avatars must leave them alone.
+CREATE TABLE IF NOT EXISTS avatars (
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
     user_id UUID REFERENCES users(id) NOT NULL,
     file_key VARCHAR(255) NOT NULL,
     created_at TIMESTAMP default CURRENT_TIMESTAMP
-);
\ No newline at end of file
+);
@@ -0,0 +1 @@
+ALTER TABLE avatars DROP COLUMN mime_type;
@@ -0,0 +1,3 @@
+-- The dropped values are gone; existing rows get an empty URL.
+ALTER TABLE users ADD COLUMN avatar_url VARCHAR(255) NOT NULL DEFAULT '';
+ALTER TABLE users ALTER COLUMN avatar_url DROP DEFAULT;
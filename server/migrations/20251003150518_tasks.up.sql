@@ -0,0 +1,16 @@
+-- Never drop the table first: running this against a database that already
+-- has tasks must leave them alone.
+DO $$
+BEGIN
+    CREATE TYPE t_status AS ENUM ('ToStart', 'Started', 'Completed');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END
+$$;
+CREATE TABLE IF NOT EXISTS tasks(
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID REFERENCES users(id) NOT NULL,
+    content VARCHAR(255) NOT NULL,
+    tasks_status t_status NOT NULL,
+    created_at TIMESTAMP default CURRENT_TIMESTAMP
+);
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE tasks
+ADD COLUMN deleted_at TIMESTAMP;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Trimmed emails stay trimmed.
+DROP INDEX users_email_lower_key;
@@ -0,0 +1,28 @@
+-- Emails are unique regardless of case. When existing emails only differ by
+-- case or surrounding spaces, the oldest account keeps the address and the
+-- others get `+dup-<id>` added to the local part, still deliverable, so the
+-- index can be built; those accounts have to be merged or fixed by hand
+-- afterwards. The local part is shortened so the address fits the column.
+WITH ranked AS (
+    SELECT id,
+           TRIM(email) AS email,
+           ROW_NUMBER() OVER (PARTITION BY LOWER(TRIM(email)) ORDER BY created_at, id) AS position
+    FROM users
+),
+duplicates AS (
+    SELECT id,
+           REGEXP_REPLACE(email, '@[^@]*$', '') AS local_part,
+           COALESCE(SUBSTRING(email FROM '@[^@]*$'), '') AS domain,
+           '+dup-' || id AS tag
+    FROM ranked
+    WHERE position > 1
+)
+UPDATE users
+SET email = LEFT(
+    LEFT(local_part, GREATEST(255 - LENGTH(tag) - LENGTH(domain), 0)) || tag || domain,
+    255
+)
+FROM duplicates
+WHERE users.id = duplicates.id;
+UPDATE users SET email = TRIM(email) WHERE email <> TRIM(email);
+CREATE UNIQUE INDEX IF NOT EXISTS users_email_lower_key ON users (LOWER(email));
@@ -0,0 +1,7 @@
+ALTER TABLE tasks DROP CONSTRAINT tasks_user_id_fkey;
+ALTER TABLE tasks
+ADD CONSTRAINT tasks_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);
+
+ALTER TABLE avatars DROP CONSTRAINT avatars_user_id_fkey;
+ALTER TABLE avatars
+ADD CONSTRAINT avatars_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id);
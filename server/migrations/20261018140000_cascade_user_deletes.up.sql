@@ -0,0 +1,8 @@
+-- Deleting a user removes everything they own.
+ALTER TABLE tasks DROP CONSTRAINT tasks_user_id_fkey;
+ALTER TABLE tasks
+ADD CONSTRAINT tasks_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE;
+
+ALTER TABLE avatars DROP CONSTRAINT avatars_user_id_fkey;
+ALTER TABLE avatars
+ADD CONSTRAINT avatars_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE;
@@ -0,0 +1 @@
+ALTER TABLE avatars DROP COLUMN thumbnail_file_key;
@@ -0,0 +1,3 @@
+-- Thumbnails are generated when an uploaded avatar is confirmed; rows from
+-- before that, and the shared default avatar, have none.
+ALTER TABLE avatars ADD COLUMN thumbnail_file_key TEXT;
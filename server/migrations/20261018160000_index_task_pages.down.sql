@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS tasks_user_id_created_at_id_idx;
+DROP INDEX IF EXISTS tasks_user_id_task_date_id_idx;
+ALTER TABLE tasks ALTER COLUMN created_at DROP NOT NULL;
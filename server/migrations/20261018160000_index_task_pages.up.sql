@@ -0,0 +1,11 @@
+-- Rows without a creation time fell out of `sort_by=created_at` pages, since
+-- comparing NULL with the cursor is never true. Their task date is the best
+-- approximation left.
+UPDATE tasks SET created_at = task_date WHERE created_at IS NULL;
+ALTER TABLE tasks ALTER COLUMN created_at SET NOT NULL;
+
+-- One index per sort order of the task list, matching its keyset cursor.
+CREATE INDEX IF NOT EXISTS tasks_user_id_task_date_id_idx
+    ON tasks (user_id, task_date, id) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS tasks_user_id_created_at_id_idx
+    ON tasks (user_id, created_at, id) WHERE deleted_at IS NULL;
@@ -0,0 +1,10 @@
+-- Refresh tokens a session rotated out. Presenting one again means the token
+-- leaked, so the session it belonged to gets revoked.
+CREATE TABLE IF NOT EXISTS rotated_refresh_tokens(
+    refresh_token_hash VARCHAR(64) PRIMARY KEY,
+    session_id UUID NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    rotated_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS rotated_refresh_tokens_session_id_idx
+    ON rotated_refresh_tokens(session_id);
@@ -1,9 +1,13 @@
+use chrono::{DateTime, Utc};
+
 #[derive(Debug, Clone)]
 pub struct Avatar {
     id: String,
     user_id: String,
     file_key: String,
     mime_type: String,
+    thumbnail_file_key: Option<String>,
+    created_at: Option<DateTime<Utc>>,
 }
 
 impl Avatar {
@@ -13,6 +17,8 @@ impl Avatar {
             user_id,
             file_key,
             mime_type,
+            thumbnail_file_key: None,
+            created_at: None,
         }
     }
 
@@ -33,6 +39,14 @@ impl Avatar {
         &self.mime_type
     }
 
+    pub fn thumbnail_file_key(&self) -> Option<&str> {
+        self.thumbnail_file_key.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<&DateTime<Utc>> {
+        self.created_at.as_ref()
+    }
+
     // Setters
     pub fn set_id(&mut self, id: String) {
         self.id = id;
@@ -49,4 +63,12 @@ impl Avatar {
     pub fn set_mime_type(&mut self, mime_type: String) {
         self.mime_type = mime_type;
     }
+
+    pub fn set_thumbnail_file_key(&mut self, thumbnail_file_key: Option<String>) {
+        self.thumbnail_file_key = thumbnail_file_key;
+    }
+
+    pub fn set_created_at(&mut self, created_at: Option<DateTime<Utc>>) {
+        self.created_at = created_at;
+    }
 }
@@ -1,4 +1,6 @@
 pub mod avatar;
+pub mod password_reset_token;
+pub mod session;
 pub mod task;
 pub mod task_status;
 pub mod user;
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+
+/// A single-use, expiring token that allows a user to choose a new password.
+/// Only the hash of the token is kept; the raw value is sent to the user.
+#[derive(Debug, Clone)]
+pub struct PasswordResetToken {
+    id: String,
+    user_id: String,
+    token_hash: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+}
+
+impl PasswordResetToken {
+    pub fn new(id: String, user_id: String, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            created_at: Utc::now(),
+            expires_at,
+            used_at: None,
+        }
+    }
+
+    pub fn new_with_all(
+        id: String,
+        user_id: String,
+        token_hash: String,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        used_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            created_at,
+            expires_at,
+            used_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    pub fn used_at(&self) -> Option<&DateTime<Utc>> {
+        self.used_at.as_ref()
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && self.expires_at > now
+    }
+
+    // Setters
+    pub fn set_used_at(&mut self, used_at: Option<DateTime<Utc>>) {
+        self.used_at = used_at;
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+
+/// A login on one device. The refresh token itself is never stored, only its
+/// hash, and it changes every time the session is refreshed.
+#[derive(Debug, Clone)]
+pub struct Session {
+    id: String,
+    user_id: String,
+    refresh_token_hash: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub fn new(
+        id: String,
+        user_id: String,
+        refresh_token_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            refresh_token_hash,
+            created_at: Utc::now(),
+            expires_at,
+            revoked_at: None,
+        }
+    }
+
+    pub fn new_with_all(
+        id: String,
+        user_id: String,
+        refresh_token_hash: String,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        revoked_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            refresh_token_hash,
+            created_at,
+            expires_at,
+            revoked_at,
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn refresh_token_hash(&self) -> &str {
+        &self.refresh_token_hash
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    pub fn revoked_at(&self) -> Option<&DateTime<Utc>> {
+        self.revoked_at.as_ref()
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+
+    // Setters
+    pub fn set_refresh_token_hash(&mut self, refresh_token_hash: String) {
+        self.refresh_token_hash = refresh_token_hash;
+    }
+
+    pub fn set_expires_at(&mut self, expires_at: DateTime<Utc>) {
+        self.expires_at = expires_at;
+    }
+
+    pub fn set_revoked_at(&mut self, revoked_at: Option<DateTime<Utc>>) {
+        self.revoked_at = revoked_at;
+    }
+}
@@ -11,6 +11,7 @@ pub struct Task {
     status: TaskStatus,
     task_date: DateTime<Utc>,
     created_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Task {
@@ -22,6 +23,7 @@ impl Task {
             task_date,
             status: TaskStatus::ToStart,
             created_at: Utc::now(),
+            deleted_at: None,
         }
     }
 
@@ -40,6 +42,7 @@ impl Task {
             status,
             task_date,
             created_at,
+            deleted_at: None,
         }
     }
 
@@ -67,6 +70,14 @@ impl Task {
         &self.task_date
     }
 
+    pub fn deleted_at(&self) -> Option<&DateTime<Utc>> {
+        self.deleted_at.as_ref()
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     pub fn set_id(&mut self, id: String) {
         self.id = Some(id);
     }
@@ -86,4 +97,8 @@ impl Task {
     pub fn set_task_date(&mut self, task_date: DateTime<Utc>) {
         self.task_date = task_date;
     }
+
+    pub fn set_deleted_at(&mut self, deleted_at: Option<DateTime<Utc>>) {
+        self.deleted_at = deleted_at;
+    }
 }
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use crate::app::repositories::repository_error::RepositoryError;
+
+/// Category of an [`AppError`]. Each kind maps to exactly one HTTP status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    UnprocessableEntity,
+    BadGateway,
+    ServiceUnavailable,
+    Internal,
+}
+
+/// A single request field that failed validation and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidParam {
+    pub name: &'static str,
+    pub reason: String,
+}
+
+impl InvalidParam {
+    pub fn new(name: &'static str, reason: impl Into<String>) -> Self {
+        Self {
+            name,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Error shared by every use case once it leaves the application layer.
+///
+/// `code` is a stable, machine readable identifier that clients can rely on;
+/// `detail` is a human readable explanation of this particular occurrence.
+#[derive(Debug)]
+pub struct AppError {
+    kind: ErrorKind,
+    code: &'static str,
+    detail: String,
+    invalid_params: Vec<InvalidParam>,
+}
+
+impl AppError {
+    pub fn new(kind: ErrorKind, code: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            code,
+            detail: detail.into(),
+            invalid_params: Vec::new(),
+        }
+    }
+
+    /// Attaches the individual fields that failed validation.
+    pub fn with_invalid_params(mut self, invalid_params: Vec<InvalidParam>) -> Self {
+        self.invalid_params = invalid_params;
+        self
+    }
+
+    pub fn bad_request(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::BadRequest, code, detail)
+    }
+
+    pub fn unauthorized(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Unauthorized, code, detail)
+    }
+
+    pub fn forbidden(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Forbidden, code, detail)
+    }
+
+    pub fn not_found(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::NotFound, code, detail)
+    }
+
+    pub fn conflict(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Conflict, code, detail)
+    }
+
+    pub fn unprocessable(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::UnprocessableEntity, code, detail)
+    }
+
+    pub fn bad_gateway(code: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::BadGateway, code, detail)
+    }
+
+    pub fn internal(detail: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Internal, "internal_error", detail)
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn detail(&self) -> &str {
+        &self.detail
+    }
+
+    pub fn invalid_params(&self) -> &[InvalidParam] {
+        &self.invalid_params
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.detail)
+    }
+}
+
+impl std::error::Error for AppError {}
+
+/// Client errors get a fixed detail: the database message names tables and
+/// constraints, so it only goes to the logs. Server errors keep it, since
+/// their detail is never sent to clients.
+impl From<RepositoryError> for AppError {
+    fn from(error: RepositoryError) -> Self {
+        if matches!(
+            error,
+            RepositoryError::NotFound(_)
+                | RepositoryError::Conflict(_)
+                | RepositoryError::ConstraintViolation(_)
+        ) {
+            tracing::warn!(error = %error, "repository rejected the request");
+        }
+        match error {
+            RepositoryError::NotFound(_) => {
+                AppError::not_found("resource_not_found", "The resource does not exist")
+            }
+            RepositoryError::Conflict(_) => {
+                AppError::conflict("resource_conflict", "The resource already exists")
+            }
+            RepositoryError::ConstraintViolation(_) => AppError::unprocessable(
+                "constraint_violation",
+                "The request breaks a data constraint",
+            ),
+            RepositoryError::Unavailable(_) => AppError::new(
+                ErrorKind::ServiceUnavailable,
+                "storage_unavailable",
+                error.to_string(),
+            ),
+            RepositoryError::Unexpected(_) => AppError::internal(error.to_string()),
+        }
+    }
+}
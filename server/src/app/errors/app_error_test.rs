@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::app::errors::app_error::{AppError, ErrorKind};
+    use crate::app::repositories::repository_error::RepositoryError;
+
+    #[test]
+    fn test_repository_errors_should_map_to_matching_kinds() {
+        let cases = [
+            (
+                RepositoryError::NotFound("task".to_string()),
+                ErrorKind::NotFound,
+                "resource_not_found",
+            ),
+            (
+                RepositoryError::Conflict("users_email_key".to_string()),
+                ErrorKind::Conflict,
+                "resource_conflict",
+            ),
+            (
+                RepositoryError::ConstraintViolation("tasks_user_id_fkey".to_string()),
+                ErrorKind::UnprocessableEntity,
+                "constraint_violation",
+            ),
+            (
+                RepositoryError::Unavailable("pool timed out".to_string()),
+                ErrorKind::ServiceUnavailable,
+                "storage_unavailable",
+            ),
+            (
+                RepositoryError::Unexpected("boom".to_string()),
+                ErrorKind::Internal,
+                "internal_error",
+            ),
+        ];
+
+        for (repository_error, kind, code) in cases {
+            let error = AppError::from(repository_error);
+            assert_eq!(error.kind(), kind);
+            assert_eq!(error.code(), code);
+        }
+    }
+
+    #[test]
+    fn test_client_repository_errors_should_not_expose_database_details() {
+        let cases = [
+            RepositoryError::NotFound("invalid id: abc".to_string()),
+            RepositoryError::Conflict(
+                "duplicate key value violates unique constraint \"users_email_lower_key\""
+                    .to_string(),
+            ),
+            RepositoryError::ConstraintViolation(
+                "insert or update on table \"tasks\" violates foreign key constraint".to_string(),
+            ),
+        ];
+
+        for repository_error in cases {
+            let error = AppError::from(repository_error);
+            assert!(!error.detail().contains("constraint \""));
+            assert!(!error.detail().contains("invalid id"));
+            assert!(!error.detail().contains("table"));
+        }
+    }
+}
@@ -1,3 +1,6 @@
 pub mod entities;
+pub mod errors;
+pub mod notifiers;
 pub mod repositories;
+pub mod storage;
 pub mod use_cases;
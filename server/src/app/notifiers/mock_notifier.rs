@@ -0,0 +1,25 @@
+use crate::app::notifiers::notifier::{Notification, Notifier, NotifierError};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+pub struct MockNotifier {
+    pub sent: Arc<Mutex<Vec<Notification>>>,
+}
+
+impl MockNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Notifier for MockNotifier {
+    fn send<'a>(
+        &'a self,
+        notification: Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotifierError>> + Send + 'a>> {
+        self.sent.lock().unwrap().push(notification);
+        Box::pin(async { Ok(()) })
+    }
+}
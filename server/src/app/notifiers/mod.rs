@@ -0,0 +1,2 @@
+pub mod mock_notifier;
+pub mod notifier;
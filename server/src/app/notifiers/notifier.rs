@@ -0,0 +1,27 @@
+use std::{fmt, future::Future, pin::Pin};
+
+/// A message that has to reach a user outside of the HTTP response.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Notification {
+    PasswordReset { email: String, token: String },
+}
+
+#[derive(Debug)]
+pub struct NotifierError(pub String);
+
+impl fmt::Display for NotifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to deliver notification: {}", self.0)
+    }
+}
+
+impl std::error::Error for NotifierError {}
+
+/// Delivery channel for notifications (email, SMS, a local file in
+/// development...). Use cases only depend on this trait.
+pub trait Notifier {
+    fn send<'a>(
+        &'a self,
+        notification: Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotifierError>> + Send + 'a>>;
+}
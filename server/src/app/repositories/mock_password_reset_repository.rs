@@ -0,0 +1,99 @@
+use crate::app::entities::password_reset_token::PasswordResetToken;
+use crate::app::repositories::password_reset_repository::PasswordResetRepository;
+use crate::app::repositories::repository_error::RepositoryError;
+use chrono::Utc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+pub struct MockPasswordResetRepository {
+    pub tokens: Arc<Mutex<Vec<PasswordResetToken>>>,
+    /// `(user_id, password_hash)` of every successful reset, standing in
+    /// for the password update and session revocation done in the database.
+    pub password_resets: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockPasswordResetRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Helper for tests to pre-populate data.
+    pub fn add_token(&self, token: PasswordResetToken) {
+        self.tokens.lock().unwrap().push(token);
+    }
+
+    // Helper for tests to inspect the state
+    pub fn find_token(&self, token_id: &str) -> Option<PasswordResetToken> {
+        self.tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.id() == token_id)
+            .cloned()
+    }
+
+    // Helper for tests to inspect the password stored by the last reset
+    pub fn last_password_reset(&self) -> Option<(String, String)> {
+        self.password_resets.lock().unwrap().last().cloned()
+    }
+}
+
+impl PasswordResetRepository for MockPasswordResetRepository {
+    fn create<'a>(
+        &'a self,
+        token: PasswordResetToken,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        self.add_token(token);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn find_by_token_hash<'a>(
+        &'a self,
+        token_hash: String,
+    ) -> Pin<
+        Box<dyn Future<Output = Result<Option<PasswordResetToken>, RepositoryError>> + Send + 'a>,
+    > {
+        let token = self
+            .tokens
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.token_hash() == token_hash)
+            .cloned();
+        Box::pin(async move { Ok(token) })
+    }
+
+    fn reset_password<'a>(
+        &'a self,
+        token_id: String,
+        user_id: String,
+        password_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut tokens = self.tokens.lock().unwrap();
+        let result = if tokens
+            .iter()
+            .any(|t| t.id() == token_id && t.used_at().is_none())
+        {
+            let now = Utc::now();
+            for token in tokens
+                .iter_mut()
+                .filter(|t| t.user_id() == user_id && t.used_at().is_none())
+            {
+                token.set_used_at(Some(now));
+            }
+            self.password_resets
+                .lock()
+                .unwrap()
+                .push((user_id, password_hash));
+            Ok(())
+        } else {
+            Err(RepositoryError::NotFound(format!(
+                "password reset token {}",
+                token_id
+            )))
+        };
+        Box::pin(async move { result })
+    }
+}
@@ -0,0 +1,134 @@
+use crate::app::entities::session::Session;
+use crate::app::repositories::repository_error::RepositoryError;
+use crate::app::repositories::session_repository::SessionRepository;
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+pub struct MockSessionRepository {
+    pub sessions: Arc<Mutex<Vec<Session>>>,
+    /// Rotated out refresh token hashes with their session id.
+    pub rotated: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockSessionRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Helper for tests to pre-populate data.
+    pub fn add_session(&self, session: Session) {
+        self.sessions.lock().unwrap().push(session);
+    }
+
+    // Helper for tests to inspect the state
+    pub fn find_session(&self, session_id: &str) -> Option<Session> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.id() == session_id)
+            .cloned()
+    }
+}
+
+impl SessionRepository for MockSessionRepository {
+    fn create<'a>(
+        &'a self,
+        session: Session,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        self.add_session(session);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash<'a>(
+        &'a self,
+        refresh_token_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Session>, RepositoryError>> + Send + 'a>> {
+        let session = self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.refresh_token_hash() == refresh_token_hash)
+            .cloned();
+        Box::pin(async move { Ok(session) })
+    }
+
+    fn find_by_rotated_refresh_token_hash<'a>(
+        &'a self,
+        refresh_token_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Session>, RepositoryError>> + Send + 'a>> {
+        let session = self
+            .rotated
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(hash, _)| *hash == refresh_token_hash)
+            .and_then(|(_, session_id)| self.find_session(session_id));
+        Box::pin(async move { Ok(session) })
+    }
+
+    fn rotate<'a>(
+        &'a self,
+        session_id: String,
+        current_hash: String,
+        new_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let result = match sessions.iter_mut().find(|s| {
+            s.id() == session_id
+                && s.refresh_token_hash() == current_hash
+                && s.revoked_at().is_none()
+        }) {
+            Some(session) => {
+                self.rotated
+                    .lock()
+                    .unwrap()
+                    .push((current_hash, session_id.clone()));
+                session.set_refresh_token_hash(new_hash);
+                session.set_expires_at(expires_at);
+                Ok(())
+            }
+            None => Err(RepositoryError::NotFound(format!("session {}", session_id))),
+        };
+        Box::pin(async move { result })
+    }
+
+    fn revoke<'a>(
+        &'a self,
+        session_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        for session in self.sessions.lock().unwrap().iter_mut() {
+            if session.id() == session_id && session.revoked_at().is_none() {
+                session.set_revoked_at(Some(Utc::now()));
+            }
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn revoke_all_for_user<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        for session in self.sessions.lock().unwrap().iter_mut() {
+            if session.user_id() == user_id && session.revoked_at().is_none() {
+                session.set_revoked_at(Some(Utc::now()));
+            }
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn is_active<'a>(
+        &'a self,
+        session_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        let active = self
+            .find_session(&session_id)
+            .is_some_and(|s| s.is_active(Utc::now()));
+        Box::pin(async move { Ok(active) })
+    }
+}
@@ -0,0 +1,207 @@
+use crate::app::entities::{task::Task, task_status::TaskStatus};
+use crate::app::repositories::repository_error::RepositoryError;
+use crate::app::repositories::task_repository::{
+    SortDirection, TaskListQuery, TaskRepository, TaskSortField,
+};
+use chrono::Utc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+pub struct MockTaskRepository {
+    pub tasks: Arc<Mutex<Vec<Task>>>,
+}
+
+impl MockTaskRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Helper for tests to pre-populate data.
+    // Sets an ID when the task does not have one and returns it.
+    pub fn add_task(&self, mut task: Task) -> String {
+        if task.id().is_none() {
+            task.set_id(uuid::Uuid::new_v4().to_string());
+        }
+        let task_id = task.id().unwrap().clone();
+        self.tasks.lock().unwrap().push(task);
+        task_id
+    }
+
+    // Helper for tests to inspect the state
+    pub fn find_task(&self, task_id: &str) -> Option<Task> {
+        self.tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|t| t.id().map(String::as_str) == Some(task_id))
+            .cloned()
+    }
+
+    // Applies `change` to the first task matching `predicate`, mirroring the
+    // `NotFound` the SQL repository reports when no row is affected.
+    fn modify(
+        &self,
+        task_id: &str,
+        predicate: impl Fn(&Task) -> bool,
+        change: impl FnOnce(&mut Task),
+    ) -> Result<(), RepositoryError> {
+        let mut tasks = self.tasks.lock().unwrap();
+        match tasks.iter_mut().find(|t| predicate(t)) {
+            Some(task) => {
+                change(task);
+                Ok(())
+            }
+            None => Err(RepositoryError::NotFound(format!("task {}", task_id))),
+        }
+    }
+}
+
+impl TaskRepository for MockTaskRepository {
+    fn save<'a>(
+        &'a self,
+        task: Task,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        self.add_task(task);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn list_all<'a>(
+        &'a self,
+        user_id: String,
+        query: TaskListQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Task>, RepositoryError>> + Send + 'a>> {
+        let sort_value = |task: &Task| match query.sort_by {
+            TaskSortField::TaskDate => *task.task_date(),
+            TaskSortField::CreatedAt => *task.created_at(),
+        };
+        let position = |task: &Task| (sort_value(task), task.id().cloned().unwrap_or_default());
+
+        let mut tasks: Vec<Task> = self
+            .tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|t| t.user_id() == user_id && !t.is_deleted())
+            .filter(|t| query.status.is_none_or(|status| *t.status() == status))
+            .filter(|t| {
+                query
+                    .task_date_from
+                    .is_none_or(|from| *t.task_date() >= from)
+            })
+            .filter(|t| query.task_date_to.is_none_or(|to| *t.task_date() <= to))
+            .filter(|t| match &query.after {
+                Some(cursor) => {
+                    let after = (cursor.sort_value, cursor.id.clone());
+                    match query.direction {
+                        SortDirection::Asc => position(t) > after,
+                        SortDirection::Desc => position(t) < after,
+                    }
+                }
+                None => true,
+            })
+            .cloned()
+            .collect();
+
+        tasks.sort_by_key(position);
+        if query.direction == SortDirection::Desc {
+            tasks.reverse();
+        }
+        tasks.truncate(query.limit as usize);
+        Box::pin(async move { Ok(tasks) })
+    }
+
+    fn find_by_id<'a>(
+        &'a self,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Task>, RepositoryError>> + Send + 'a>> {
+        let task = self.find_task(&task_id);
+        Box::pin(async move { Ok(task) })
+    }
+
+    fn update_status<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+        status: TaskStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let result = self.modify(
+            &task_id,
+            |t| t.id() == Some(&task_id) && t.user_id() == user_id && !t.is_deleted(),
+            |t| t.set_status(status),
+        );
+        Box::pin(async move { result })
+    }
+
+    fn update<'a>(
+        &'a self,
+        task: Task,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let task_id = task.id().cloned().unwrap_or_default();
+        let user_id = task.user_id().to_string();
+        let result = self.modify(
+            &task_id,
+            |t| t.id() == Some(&task_id) && t.user_id() == user_id && !t.is_deleted(),
+            |t| *t = task,
+        );
+        Box::pin(async move { result })
+    }
+
+    fn soft_delete<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let result = self.modify(
+            &task_id,
+            |t| t.id() == Some(&task_id) && t.user_id() == user_id && !t.is_deleted(),
+            |t| t.set_deleted_at(Some(Utc::now())),
+        );
+        Box::pin(async move { result })
+    }
+
+    fn list_trash<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Task>, RepositoryError>> + Send + 'a>> {
+        let tasks: Vec<Task> = self
+            .tasks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|t| t.user_id() == user_id && t.is_deleted())
+            .cloned()
+            .collect();
+        Box::pin(async move { Ok(tasks) })
+    }
+
+    fn restore<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let result = self.modify(
+            &task_id,
+            |t| t.id() == Some(&task_id) && t.user_id() == user_id && t.is_deleted(),
+            |t| t.set_deleted_at(None),
+        );
+        Box::pin(async move { result })
+    }
+
+    fn purge<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut tasks = self.tasks.lock().unwrap();
+        let before = tasks.len();
+        tasks.retain(|t| !(t.id() == Some(&task_id) && t.user_id() == user_id && t.is_deleted()));
+        let result = if tasks.len() < before {
+            Ok(())
+        } else {
+            Err(RepositoryError::NotFound(format!("task {}", task_id)))
+        };
+        Box::pin(async move { result })
+    }
+}
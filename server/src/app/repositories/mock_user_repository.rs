@@ -1,5 +1,5 @@
-use crate::app::entities::avatar;
 use crate::app::entities::{avatar::Avatar, user::User};
+use crate::app::repositories::repository_error::RepositoryError;
 use crate::app::repositories::user_repository::{UserProfile, UserRepository};
 use argon2::{
     Argon2,
@@ -49,7 +49,7 @@ impl MockUserRepository {
             .lock()
             .unwrap()
             .iter()
-            .find(|u| u.email() == email)
+            .find(|u| u.email().eq_ignore_ascii_case(email))
             .cloned()
     }
 }
@@ -58,31 +58,52 @@ impl UserRepository for MockUserRepository {
     fn register<'a>(
         &'a self,
         mut user: User,
-        _file_key: String,
-        _mime_type: String,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
-        // The use case should have already hashed the password.
-        // The repository is responsible for assigning an ID.
+        file_key: String,
+        mime_type: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        // The use case should have already hashed the password and usually
+        // assigned the ID; the repository fills it in otherwise.
         if user.id().is_none() {
             user.set_id(uuid::Uuid::new_v4().to_string());
         }
-        self.users.lock().unwrap().push(user);
-        Box::pin(async {})
+        let mut users = self.users.lock().unwrap();
+        // Mirrors the unique index on LOWER(email).
+        if users
+            .iter()
+            .any(|u| u.email().eq_ignore_ascii_case(user.email()))
+        {
+            return Box::pin(async {
+                Err(RepositoryError::Conflict(
+                    "users_email_lower_key".to_string(),
+                ))
+            });
+        }
+        self.avatars.lock().unwrap().push(Avatar::new(
+            uuid::Uuid::new_v4().to_string(),
+            user.id().unwrap().clone(),
+            file_key,
+            mime_type,
+        ));
+        users.push(user);
+        Box::pin(async { Ok(()) })
     }
 
     fn get_user_by_email<'a>(
         &'a self,
         email: String,
-    ) -> Pin<Box<dyn Future<Output = Option<User>> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = Result<Option<User>, RepositoryError>> + Send + 'a>> {
         let users = self.users.lock().unwrap();
-        let user = users.iter().find(|u| u.email() == email).cloned();
-        Box::pin(async move { user })
+        let user = users
+            .iter()
+            .find(|u| u.email().eq_ignore_ascii_case(&email))
+            .cloned();
+        Box::pin(async move { Ok(user) })
     }
 
-    fn get_user_profile<'a>(
-        &'a self,
+    fn get_user_profile(
+        &self,
         user_id: String,
-    ) -> Pin<Box<dyn Future<Output = Option<UserProfile>> + Send>> {
+    ) -> Pin<Box<dyn Future<Output = Result<Option<UserProfile>, RepositoryError>> + Send>> {
         let users = self.users.lock().unwrap();
         let user = users.iter().find(|u| u.id() == Some(&user_id)).cloned();
 
@@ -95,13 +116,139 @@ impl UserRepository for MockUserRepository {
                         let user_profile = UserProfile {
                             user,
                             avatar_url: avatar.file_key().to_string(),
+                            thumbnail_url: avatar.thumbnail_file_key().map(str::to_string),
                         };
-                        Box::pin(async move { Some(user_profile) })
+                        Box::pin(async move { Ok(Some(user_profile)) })
                     }
-                    None => Box::pin(async move { None }),
+                    None => Box::pin(async move { Ok(None) }),
                 }
             }
-            None => Box::pin(async move { None }),
+            None => Box::pin(async move { Ok(None) }),
         }
     }
+
+    fn get_user_by_id<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<User>, RepositoryError>> + Send + 'a>> {
+        let users = self.users.lock().unwrap();
+        let user = users.iter().find(|u| u.id() == Some(&user_id)).cloned();
+        Box::pin(async move { Ok(user) })
+    }
+
+    fn update_profile<'a>(
+        &'a self,
+        user: User,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut users = self.users.lock().unwrap();
+        let taken = users
+            .iter()
+            .any(|u| u.id() != user.id() && u.email().eq_ignore_ascii_case(user.email()));
+        let result = if taken {
+            Err(RepositoryError::Conflict(
+                "users_email_lower_key".to_string(),
+            ))
+        } else {
+            match users.iter_mut().find(|u| u.id() == user.id()) {
+                Some(existing) => {
+                    existing.set_username(user.username().to_string());
+                    existing.set_email(user.email().to_string());
+                    Ok(())
+                }
+                None => Err(RepositoryError::NotFound(format!("user {:?}", user.id()))),
+            }
+        };
+        Box::pin(async move { result })
+    }
+
+    fn update_password<'a>(
+        &'a self,
+        user_id: String,
+        password_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut users = self.users.lock().unwrap();
+        let result = match users.iter_mut().find(|u| u.id() == Some(&user_id)) {
+            Some(user) => {
+                user.set_password(password_hash);
+                Ok(())
+            }
+            None => Err(RepositoryError::NotFound(format!("user {}", user_id))),
+        };
+        Box::pin(async move { result })
+    }
+
+    fn list_avatars<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Avatar>, RepositoryError>> + Send + 'a>> {
+        let avatars = self
+            .avatars
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.user_id() == user_id)
+            .cloned()
+            .collect();
+        Box::pin(async move { Ok(avatars) })
+    }
+
+    fn list_avatars_with_key_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Avatar>, RepositoryError>> + Send + 'a>> {
+        let avatars = self
+            .avatars
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.file_key().starts_with(&prefix))
+            .cloned()
+            .collect();
+        Box::pin(async move { Ok(avatars) })
+    }
+
+    fn update_avatar<'a>(
+        &'a self,
+        user_id: String,
+        file_key: String,
+        mime_type: String,
+        thumbnail_file_key: Option<String>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut avatars = self.avatars.lock().unwrap();
+        let mut updated = false;
+        for avatar in avatars.iter_mut().filter(|a| a.user_id() == user_id) {
+            avatar.set_file_key(file_key.clone());
+            avatar.set_mime_type(mime_type.clone());
+            avatar.set_thumbnail_file_key(thumbnail_file_key.clone());
+            updated = true;
+        }
+        let result = if updated {
+            Ok(())
+        } else {
+            Err(RepositoryError::NotFound(format!(
+                "avatar of user {}",
+                user_id
+            )))
+        };
+        Box::pin(async move { result })
+    }
+
+    fn delete_user<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        let mut users = self.users.lock().unwrap();
+        let before = users.len();
+        users.retain(|u| u.id() != Some(&user_id));
+        let result = if users.len() == before {
+            Err(RepositoryError::NotFound(format!("user {}", user_id)))
+        } else {
+            self.avatars
+                .lock()
+                .unwrap()
+                .retain(|a| a.user_id() != user_id);
+            Ok(())
+        };
+        Box::pin(async move { result })
+    }
 }
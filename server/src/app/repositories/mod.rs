@@ -1,3 +1,9 @@
+pub mod mock_password_reset_repository;
+pub mod mock_session_repository;
+pub mod mock_task_repository;
 pub mod mock_user_repository;
+pub mod password_reset_repository;
+pub mod repository_error;
+pub mod session_repository;
 pub mod task_repository;
 pub mod user_repository;
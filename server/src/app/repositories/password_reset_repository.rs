@@ -0,0 +1,32 @@
+use std::{future::Future, pin::Pin};
+
+use crate::app::{
+    entities::password_reset_token::PasswordResetToken,
+    repositories::repository_error::RepositoryError,
+};
+
+pub trait PasswordResetRepository {
+    fn create<'a>(
+        &'a self,
+        token: PasswordResetToken,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    fn find_by_token_hash<'a>(
+        &'a self,
+        token_hash: String,
+    ) -> Pin<
+        Box<dyn Future<Output = Result<Option<PasswordResetToken>, RepositoryError>> + Send + 'a>,
+    >;
+
+    /// Redeems the token in one transaction: marks it and every other
+    /// outstanding token of the user as used, stores the new password hash
+    /// and revokes all of the user's sessions. Fails with `NotFound` when
+    /// the token was already used, so two concurrent confirmations cannot
+    /// both succeed, and leaves everything untouched on any failure.
+    fn reset_password<'a>(
+        &'a self,
+        token_id: String,
+        user_id: String,
+        password_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// Failure reported by a repository implementation, independent of the
+/// storage technology behind it.
+#[derive(Debug)]
+pub enum RepositoryError {
+    NotFound(String),
+    Conflict(String),
+    ConstraintViolation(String),
+    Unavailable(String),
+    Unexpected(String),
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::NotFound(e) => write!(f, "Record not found: {}", e),
+            RepositoryError::Conflict(e) => write!(f, "Record already exists: {}", e),
+            RepositoryError::ConstraintViolation(e) => {
+                write!(f, "Constraint violation: {}", e)
+            }
+            RepositoryError::Unavailable(e) => write!(f, "Storage unavailable: {}", e),
+            RepositoryError::Unexpected(e) => write!(f, "Unexpected storage error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
@@ -0,0 +1,49 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::{DateTime, Utc};
+
+use crate::app::{entities::session::Session, repositories::repository_error::RepositoryError};
+
+pub trait SessionRepository {
+    fn create<'a>(
+        &'a self,
+        session: Session,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    fn find_by_refresh_token_hash<'a>(
+        &'a self,
+        refresh_token_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Session>, RepositoryError>> + Send + 'a>>;
+
+    /// Session that `refresh_token_hash` was rotated out of, if any.
+    fn find_by_rotated_refresh_token_hash<'a>(
+        &'a self,
+        refresh_token_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Session>, RepositoryError>> + Send + 'a>>;
+
+    /// Swaps the refresh token of a live session and remembers the old one.
+    /// Fails with `NotFound` when `current_hash` no longer matches, so a
+    /// token can only be rotated once.
+    fn rotate<'a>(
+        &'a self,
+        session_id: String,
+        current_hash: String,
+        new_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    fn revoke<'a>(
+        &'a self,
+        session_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    fn revoke_all_for_user<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    fn is_active<'a>(
+        &'a self,
+        session_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+}
@@ -1,21 +1,90 @@
 use std::pin::Pin;
 
-use crate::app::entities::{task::Task, task_status::TaskStatus};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::app::{
+    entities::{task::Task, task_status::TaskStatus},
+    repositories::repository_error::RepositoryError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSortField {
+    #[default]
+    TaskDate,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Position of the last task of a page: the value of the sort field plus the
+/// task id, which breaks ties between tasks sharing the same timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskCursor {
+    pub sort_value: DateTime<Utc>,
+    pub id: String,
+}
+
+/// Filters, ordering and page bounds applied by `TaskRepository::list_all`.
+#[derive(Debug, Clone, Default)]
+pub struct TaskListQuery {
+    pub status: Option<TaskStatus>,
+    pub task_date_from: Option<DateTime<Utc>>,
+    pub task_date_to: Option<DateTime<Utc>>,
+    pub sort_by: TaskSortField,
+    pub direction: SortDirection,
+    pub after: Option<TaskCursor>,
+    pub limit: i64,
+}
 
 pub trait TaskRepository {
-    fn save<'a>(&'a self, task: Task) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+    fn save<'a>(
+        &'a self,
+        task: Task,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
     fn list_all<'a>(
         &'a self,
         user_id: String,
-    ) -> Pin<Box<dyn Future<Output = Vec<Task>> + Send + 'a>>;
+        query: TaskListQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Task>, RepositoryError>> + Send + 'a>>;
     fn find_by_id<'a>(
         &'a self,
-        user_id: String,
-    ) -> Pin<Box<dyn Future<Output = Option<Task>> + Send + 'a>>;
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Task>, RepositoryError>> + Send + 'a>>;
     fn update_status<'a>(
         &'a self,
         user_id: String,
         task_id: String,
         status: TaskStatus,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    fn update<'a>(
+        &'a self,
+        task: Task,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    fn soft_delete<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    fn list_trash<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Task>, RepositoryError>> + Send + 'a>>;
+    fn restore<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    fn purge<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
 }
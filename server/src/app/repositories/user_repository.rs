@@ -1,16 +1,24 @@
 use std::{future::Future, pin::Pin};
 
-use crate::app::entities::user::User;
+use crate::app::{
+    entities::{avatar::Avatar, user::User},
+    repositories::repository_error::RepositoryError,
+};
 
 pub struct UserProfile {
     pub user: User,
     pub avatar_url: String,
+    pub thumbnail_url: Option<String>,
 }
 
 impl UserProfile {
     pub fn set_avatar_url(&mut self, avatar_url: String) {
         self.avatar_url = avatar_url;
     }
+
+    pub fn set_thumbnail_url(&mut self, thumbnail_url: Option<String>) {
+        self.thumbnail_url = thumbnail_url;
+    }
 }
 
 pub trait UserRepository {
@@ -19,13 +27,50 @@ pub trait UserRepository {
         user: User,
         file_key: String,
         mime_type: String,
-    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
     fn get_user_by_email<'a>(
         &'a self,
         email: String,
-    ) -> Pin<Box<dyn Future<Output = Option<User>> + Send + 'a>>;
-    fn get_user_profile<'a>(
+    ) -> Pin<Box<dyn Future<Output = Result<Option<User>, RepositoryError>> + Send + 'a>>;
+    fn get_user_profile(
+        &self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<UserProfile>, RepositoryError>> + Send>>;
+    fn get_user_by_id<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<User>, RepositoryError>> + Send + 'a>>;
+    /// Persists the user's username and email. Fails with `Conflict` when the
+    /// email already belongs to another account.
+    fn update_profile<'a>(
+        &'a self,
+        user: User,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    fn update_password<'a>(
+        &'a self,
+        user_id: String,
+        password_hash: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    fn list_avatars<'a>(
+        &'a self,
+        user_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Avatar>, RepositoryError>> + Send + 'a>>;
+    /// Avatars of every user whose object key starts with `prefix`.
+    fn list_avatars_with_key_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Avatar>, RepositoryError>> + Send + 'a>>;
+    /// Points the user's avatar at another stored object and its thumbnail.
+    fn update_avatar<'a>(
+        &'a self,
+        user_id: String,
+        file_key: String,
+        mime_type: String,
+        thumbnail_file_key: Option<String>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+    /// Deletes the user together with their tasks, avatars and sessions.
+    fn delete_user<'a>(
         &'a self,
         user_id: String,
-    ) -> Pin<Box<dyn Future<Output = Option<UserProfile>> + Send>>;
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
 }
@@ -0,0 +1,131 @@
+use crate::app::storage::object_storage::{ObjectBytes, ObjectStorage, StorageError};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Stored objects as `(content_type, bytes)` by key.
+type StoredObjects = HashMap<String, (String, Vec<u8>)>;
+
+#[derive(Clone, Default)]
+pub struct MockObjectStorage {
+    pub objects: Arc<Mutex<StoredObjects>>,
+    pub deleted: Arc<Mutex<Vec<String>>>,
+    /// Prefixes passed to `delete_with_prefix`, in call order.
+    pub deleted_prefixes: Arc<Mutex<Vec<String>>>,
+    /// Keys `presign_get` signed a URL for, in call order.
+    pub presigned_gets: Arc<Mutex<Vec<String>>>,
+    /// When set, every call fails with this message.
+    pub failure: Arc<Mutex<Option<String>>>,
+}
+
+impl MockObjectStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&self, key: &str, content_type: &str, bytes: Vec<u8>) {
+        self.objects
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (content_type.to_string(), bytes));
+    }
+
+    pub fn fail_with(&self, message: &str) {
+        *self.failure.lock().unwrap() = Some(message.to_string());
+    }
+
+    fn check_failure(&self) -> Result<(), StorageError> {
+        match self.failure.lock().unwrap().clone() {
+            Some(message) => Err(StorageError(message)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ObjectStorage for MockObjectStorage {
+    fn presign_put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        let result = self.check_failure().map(|_| {
+            format!(
+                "mock://put/{}?content_type={}&expires_in={}",
+                key,
+                content_type,
+                expires_in.as_secs()
+            )
+        });
+        Box::pin(async move { result })
+    }
+
+    fn presign_get<'a>(
+        &'a self,
+        key: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        let result = self.check_failure().map(|_| {
+            self.presigned_gets.lock().unwrap().push(key.clone());
+            format!("mock://get/{}?expires_in={}", key, expires_in.as_secs())
+        });
+        Box::pin(async move { result })
+    }
+
+    fn get<'a>(
+        &'a self,
+        key: String,
+        max_bytes: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<ObjectBytes, StorageError>> + Send + 'a>> {
+        let result = self
+            .check_failure()
+            .map(|_| match self.objects.lock().unwrap().get(&key) {
+                None => ObjectBytes::Missing,
+                Some((_, bytes)) if bytes.len() > max_bytes => ObjectBytes::TooLarge,
+                Some((_, bytes)) => ObjectBytes::Found(bytes.clone()),
+            });
+        Box::pin(async move { result })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        let result = self.check_failure();
+        if result.is_ok() {
+            self.insert(&key, &content_type, bytes);
+        }
+        Box::pin(async move { result })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        key: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        let result = self.check_failure();
+        if result.is_ok() {
+            self.objects.lock().unwrap().remove(&key);
+            self.deleted.lock().unwrap().push(key);
+        }
+        Box::pin(async move { result })
+    }
+
+    fn delete_with_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        let result = self.check_failure();
+        if result.is_ok() {
+            self.objects
+                .lock()
+                .unwrap()
+                .retain(|key, _| !key.starts_with(&prefix));
+            self.deleted_prefixes.lock().unwrap().push(prefix);
+        }
+        Box::pin(async move { result })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod mock_object_storage;
+pub mod object_storage;
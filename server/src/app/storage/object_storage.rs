@@ -0,0 +1,77 @@
+use std::{fmt, future::Future, pin::Pin, time::Duration};
+
+#[derive(Debug)]
+pub struct StorageError(pub String);
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Object storage request failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// Key `ping` reads. Nothing is ever stored under it.
+const PING_KEY: &str = "health-check";
+
+/// What `get` found under a key.
+#[derive(Debug, PartialEq)]
+pub enum ObjectBytes {
+    Missing,
+    /// Bigger than the caller's limit. Only as much of the body as it took
+    /// to tell was read.
+    TooLarge,
+    Found(Vec<u8>),
+}
+
+/// Where uploaded files (avatars) live. Use cases only depend on this trait;
+/// clients move the bytes themselves through presigned URLs, the server only
+/// reads uploads back to process them.
+pub trait ObjectStorage {
+    /// URL the client can `PUT` the object to until `expires_in` elapses.
+    /// The upload must be sent with the same `Content-Type`.
+    fn presign_put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>>;
+    /// URL the client can `GET` the object from until `expires_in` elapses.
+    fn presign_get<'a>(
+        &'a self,
+        key: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>>;
+    /// Downloads the object stored under `key` unless it is bigger than
+    /// `max_bytes`, so an oversized upload is never buffered in full.
+    fn get<'a>(
+        &'a self,
+        key: String,
+        max_bytes: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<ObjectBytes, StorageError>> + Send + 'a>>;
+    /// Stores `bytes` under `key`, replacing any previous object.
+    fn put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>>;
+    /// Removes the object stored under `key`. Deleting a missing object is
+    /// not an error.
+    fn delete<'a>(
+        &'a self,
+        key: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>>;
+    /// Removes every object whose key starts with `prefix`, including ones
+    /// the server never recorded, such as uploads that were not confirmed.
+    fn delete_with_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>>;
+    /// Succeeds when the backend answers. Reads a key that is never written,
+    /// so a missing object counts as an answer.
+    fn ping<'a>(&'a self) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        let get = self.get(PING_KEY.to_string(), 0);
+        Box::pin(async move { get.await.map(|_| ()) })
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::Cursor;
+
+use image::{
+    DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits, Rgb, RgbImage,
+    imageops::FilterType,
+};
+use sha2::{Digest, Sha256};
+
+/// Largest avatar upload that is processed; bigger files are rejected.
+pub const AVATAR_MAX_BYTES: usize = 5 * 1024 * 1024;
+/// Content types an avatar may be uploaded as: the formats
+/// `process_avatar_image` decodes. Anything else, SVG included, could be
+/// served back as is before it is confirmed.
+pub const AVATAR_MIME_TYPES: [&str; 4] = ["image/jpeg", "image/png", "image/gif", "image/webp"];
+/// Width and height of the square thumbnail generated for each avatar.
+pub const THUMBNAIL_SIZE: u32 = 128;
+/// Upper bound on decoded dimensions, so a small file cannot expand into a
+/// huge bitmap.
+const AVATAR_MAX_DIMENSION: u32 = 8192;
+const THUMBNAIL_SUFFIX: &str = "-thumbnail";
+/// Width and height of generated default avatars.
+pub const IDENTICON_SIZE: u32 = 240;
+pub const IDENTICON_MIME_TYPE: &str = "image/png";
+/// Identicons are a 5x5 grid mirrored around the middle column.
+const IDENTICON_GRID: u32 = 5;
+const IDENTICON_BACKGROUND: Rgb<u8> = Rgb([240, 240, 240]);
+
+/// Key the thumbnail of the avatar stored under `file_key` is kept at.
+pub fn thumbnail_file_key(file_key: &str) -> String {
+    format!("{file_key}{THUMBNAIL_SUFFIX}")
+}
+
+pub fn is_thumbnail_file_key(file_key: &str) -> bool {
+    file_key.ends_with(THUMBNAIL_SUFFIX)
+}
+
+/// Key of the generated default avatar of a user.
+pub fn identicon_file_key(user_id: &str) -> String {
+    format!("{user_id}-identicon")
+}
+
+/// Draws the PNG identicon for `seed` (the user id). The same seed always
+/// gives the same image: the hash picks the colour and which cells are set.
+pub fn generate_identicon(seed: &str) -> Vec<u8> {
+    let hash = Sha256::digest(seed.as_bytes());
+    let foreground = hue_to_rgb(u16::from_be_bytes([hash[0], hash[1]]) % 360);
+
+    let cell = IDENTICON_SIZE / (IDENTICON_GRID + 1);
+    let margin = (IDENTICON_SIZE - cell * IDENTICON_GRID) / 2;
+    let half = IDENTICON_GRID.div_ceil(2);
+    let mut image = RgbImage::from_pixel(IDENTICON_SIZE, IDENTICON_SIZE, IDENTICON_BACKGROUND);
+    for row in 0..IDENTICON_GRID {
+        for column in 0..half {
+            if hash[(2 + row * half + column) as usize] % 2 == 0 {
+                continue;
+            }
+            for mirrored in [column, IDENTICON_GRID - 1 - column] {
+                let (left, top) = (margin + mirrored * cell, margin + row * cell);
+                for y in top..top + cell {
+                    for x in left..left + cell {
+                        image.put_pixel(x, y, foreground);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut bytes = Cursor::new(Vec::new());
+    DynamicImage::ImageRgb8(image)
+        .write_to(&mut bytes, ImageFormat::Png)
+        .expect("encoding a PNG in memory cannot fail");
+    bytes.into_inner()
+}
+
+/// Converts a hue in degrees to a muted colour (HSL with fixed saturation and
+/// lightness).
+fn hue_to_rgb(hue: u16) -> Rgb<u8> {
+    let (saturation, lightness) = (0.55, 0.5);
+    let chroma = (1.0 - f32::abs(2.0 * lightness - 1.0)) * saturation;
+    let sector = f32::from(hue) / 60.0;
+    let x = chroma * (1.0 - f32::abs(sector % 2.0 - 1.0));
+    let (r, g, b) = match hue / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |value: f32| ((value + m) * 255.0).round() as u8;
+    Rgb([channel(r), channel(g), channel(b)])
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AvatarImageError {
+    TooLarge,
+    Unsupported,
+}
+
+/// An avatar re-encoded from the decoded pixels, which drops EXIF and any
+/// other metadata the upload carried.
+pub struct ProcessedAvatar {
+    /// Content type sniffed from the upload, not the one the client claimed.
+    pub mime_type: &'static str,
+    pub image: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+}
+
+/// Validates an uploaded avatar and prepares what gets stored. JPEGs stay
+/// JPEGs; PNG, GIF and WebP uploads are stored as PNG (GIFs lose their
+/// animation). The EXIF orientation is applied before the metadata goes away.
+pub fn process_avatar_image(bytes: &[u8]) -> Result<ProcessedAvatar, AvatarImageError> {
+    if bytes.len() > AVATAR_MAX_BYTES {
+        return Err(AvatarImageError::TooLarge);
+    }
+
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|_| AvatarImageError::Unsupported)?;
+    let (output_format, mime_type) = match reader.format() {
+        Some(ImageFormat::Jpeg) => (ImageFormat::Jpeg, "image/jpeg"),
+        Some(ImageFormat::Png | ImageFormat::Gif | ImageFormat::WebP) => {
+            (ImageFormat::Png, "image/png")
+        }
+        _ => return Err(AvatarImageError::Unsupported),
+    };
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(AVATAR_MAX_DIMENSION);
+    limits.max_image_height = Some(AVATAR_MAX_DIMENSION);
+    reader.limits(limits);
+
+    let mut decoder = reader
+        .into_decoder()
+        .map_err(|_| AvatarImageError::Unsupported)?;
+    let orientation = decoder
+        .orientation()
+        .map_err(|_| AvatarImageError::Unsupported)?;
+    let mut image =
+        DynamicImage::from_decoder(decoder).map_err(|_| AvatarImageError::Unsupported)?;
+    image.apply_orientation(orientation);
+
+    let thumbnail = image.resize_to_fill(THUMBNAIL_SIZE, THUMBNAIL_SIZE, FilterType::Lanczos3);
+
+    Ok(ProcessedAvatar {
+        mime_type,
+        image: encode(image, output_format)?,
+        thumbnail: encode(thumbnail, output_format)?,
+    })
+}
+
+fn encode(image: DynamicImage, format: ImageFormat) -> Result<Vec<u8>, AvatarImageError> {
+    // JPEG has no alpha channel.
+    let image = match format {
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.into_rgb8()),
+        _ => image,
+    };
+    let mut bytes = Cursor::new(Vec::new());
+    image
+        .write_to(&mut bytes, format)
+        .map_err(|_| AvatarImageError::Unsupported)?;
+    Ok(bytes.into_inner())
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    repositories::{repository_error::RepositoryError, user_repository::UserRepository},
+    storage::object_storage::{ObjectBytes, ObjectStorage, StorageError},
+    use_cases::avatar_image::{IDENTICON_MIME_TYPE, generate_identicon, identicon_file_key},
+};
+
+pub struct BackfillIdenticonsRequest {
+    legacy_default_avatar: String,
+}
+
+impl BackfillIdenticonsRequest {
+    /// `legacy_default_avatar` is the `DEFAULT_AVATAR` that registration
+    /// used to prefix the keys of users who signed up without a picture.
+    pub fn new(legacy_default_avatar: String) -> Self {
+        Self {
+            legacy_default_avatar,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BackfillIdenticonsResponse {
+    /// Avatars that pointed at nothing and now use the user's identicon.
+    pub replaced: usize,
+    /// Avatars with a legacy key whose object was uploaded after all.
+    pub kept: usize,
+}
+
+#[derive(Debug)]
+pub enum BackfillIdenticonsError {
+    Storage(StorageError),
+    Repository(RepositoryError),
+}
+
+impl From<StorageError> for BackfillIdenticonsError {
+    fn from(error: StorageError) -> Self {
+        BackfillIdenticonsError::Storage(error)
+    }
+}
+
+impl From<RepositoryError> for BackfillIdenticonsError {
+    fn from(error: RepositoryError) -> Self {
+        BackfillIdenticonsError::Repository(error)
+    }
+}
+
+impl fmt::Display for BackfillIdenticonsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackfillIdenticonsError::Storage(e) => write!(f, "{}", e),
+            BackfillIdenticonsError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Gives an identicon to accounts registered before identicons existed.
+/// Those were stored under `{DEFAULT_AVATAR}-{uuid}`, a key nothing was ever
+/// uploaded to unless the client used the URL it got back. Handled rows no
+/// longer match, so an interrupted run can simply be started again.
+pub struct BackfillIdenticonsUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+}
+
+impl BackfillIdenticonsUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            object_storage,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: BackfillIdenticonsRequest,
+    ) -> Result<BackfillIdenticonsResponse, BackfillIdenticonsError> {
+        let avatars = self
+            .user_repository
+            .list_avatars_with_key_prefix(format!("{}-", request.legacy_default_avatar))
+            .await?;
+
+        let mut response = BackfillIdenticonsResponse {
+            replaced: 0,
+            kept: 0,
+        };
+        for avatar in avatars {
+            // A zero byte limit only asks whether the object exists.
+            let stored = self
+                .object_storage
+                .get(avatar.file_key().to_string(), 0)
+                .await?;
+            if stored != ObjectBytes::Missing {
+                response.kept += 1;
+                continue;
+            }
+
+            let identicon_key = identicon_file_key(avatar.user_id());
+            self.object_storage
+                .put(
+                    identicon_key.clone(),
+                    IDENTICON_MIME_TYPE.to_string(),
+                    generate_identicon(avatar.user_id()),
+                )
+                .await?;
+            self.user_repository
+                .update_avatar(
+                    avatar.user_id().to_string(),
+                    identicon_key,
+                    IDENTICON_MIME_TYPE.to_string(),
+                    None,
+                )
+                .await?;
+            response.replaced += 1;
+        }
+        Ok(response)
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::app::entities::user::User;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::storage::mock_object_storage::MockObjectStorage;
+    use crate::app::use_cases::avatar_image::{
+        IDENTICON_MIME_TYPE, generate_identicon, identicon_file_key,
+    };
+    use crate::app::use_cases::backfill_identicons_use_case::{
+        BackfillIdenticonsError, BackfillIdenticonsRequest, BackfillIdenticonsResponse,
+        BackfillIdenticonsUseCase,
+    };
+
+    /// Adds a user whose avatar points at `file_key` and returns their id.
+    fn add_user_with_avatar(user_repo: &MockUserRepository, email: &str, file_key: &str) -> String {
+        user_repo.add_user(User::new(
+            email.to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+        ));
+        let user_id = user_repo
+            .find_user_by_email(email)
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+        let mut avatars = user_repo.avatars.lock().unwrap();
+        let avatar = avatars.iter_mut().find(|a| a.user_id() == user_id).unwrap();
+        avatar.set_file_key(file_key.to_string());
+        avatar.set_mime_type("image/jpeg".to_string());
+        user_id
+    }
+
+    fn avatar_key_of(user_repo: &MockUserRepository, user_id: &str) -> String {
+        let avatars = user_repo.avatars.lock().unwrap();
+        let avatar = avatars.iter().find(|a| a.user_id() == user_id).unwrap();
+        avatar.file_key().to_string()
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_replace_only_dangling_legacy_avatars() {
+        // Arrange
+        let user_repo = Arc::new(MockUserRepository::new());
+        let storage = Arc::new(MockObjectStorage::new());
+        let dangling = add_user_with_avatar(&user_repo, "a@example.com", "default.jpg-1");
+        let uploaded = add_user_with_avatar(&user_repo, "b@example.com", "default.jpg-2");
+        storage.insert("default.jpg-2", "image/jpeg", b"jpeg".to_vec());
+        let modern = add_user_with_avatar(&user_repo, "c@example.com", "c-identicon");
+        let use_case = BackfillIdenticonsUseCase::new(user_repo.clone(), storage.clone());
+
+        // Act
+        let result = use_case
+            .execute(BackfillIdenticonsRequest::new("default.jpg".to_string()))
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            BackfillIdenticonsResponse {
+                replaced: 1,
+                kept: 1
+            }
+        );
+        assert_eq!(
+            avatar_key_of(&user_repo, &dangling),
+            identicon_file_key(&dangling)
+        );
+        assert_eq!(
+            storage.objects.lock().unwrap()[&identicon_file_key(&dangling)],
+            (
+                IDENTICON_MIME_TYPE.to_string(),
+                generate_identicon(&dangling)
+            )
+        );
+        assert_eq!(avatar_key_of(&user_repo, &uploaded), "default.jpg-2");
+        assert_eq!(avatar_key_of(&user_repo, &modern), "c-identicon");
+    }
+
+    #[actix_web::test]
+    async fn test_execute_when_storage_fails_should_leave_avatars_alone() {
+        // Arrange
+        let user_repo = Arc::new(MockUserRepository::new());
+        let storage = Arc::new(MockObjectStorage::new());
+        let user_id = add_user_with_avatar(&user_repo, "a@example.com", "default.jpg-1");
+        storage.fail_with("storage is down");
+        let use_case = BackfillIdenticonsUseCase::new(user_repo.clone(), storage);
+
+        // Act
+        let result = use_case
+            .execute(BackfillIdenticonsRequest::new("default.jpg".to_string()))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(BackfillIdenticonsError::Storage(_))));
+        assert_eq!(avatar_key_of(&user_repo, &user_id), "default.jpg-1");
+    }
+}
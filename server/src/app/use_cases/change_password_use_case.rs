@@ -0,0 +1,133 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::{AppError, InvalidParam},
+    repositories::{
+        repository_error::RepositoryError, session_repository::SessionRepository,
+        user_repository::UserRepository,
+    },
+    use_cases::{
+        password_hashing::{hash_password, verify_password},
+        user_validation::validate_password,
+    },
+};
+
+pub struct ChangePasswordRequest {
+    user_id: String,
+    current_password: String,
+    new_password: String,
+}
+
+impl ChangePasswordRequest {
+    pub fn new(user_id: String, current_password: String, new_password: String) -> Self {
+        Self {
+            user_id,
+            current_password,
+            new_password,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ChangePasswordError {
+    UserNotFound(),
+    InvalidCurrentPassword(),
+    WeakPassword(InvalidParam),
+    PasswordHashing(String),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for ChangePasswordError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => ChangePasswordError::UserNotFound(),
+            error => ChangePasswordError::Repository(error),
+        }
+    }
+}
+
+impl From<ChangePasswordError> for AppError {
+    fn from(error: ChangePasswordError) -> Self {
+        match error {
+            ChangePasswordError::UserNotFound() => {
+                AppError::not_found("user_not_found", error.to_string())
+            }
+            ChangePasswordError::InvalidCurrentPassword() => {
+                AppError::forbidden("invalid_current_password", error.to_string())
+            }
+            ChangePasswordError::WeakPassword(invalid_param) => {
+                AppError::unprocessable("validation_failed", "One or more fields are invalid")
+                    .with_invalid_params(vec![invalid_param])
+            }
+            ChangePasswordError::PasswordHashing(_) => AppError::internal(error.to_string()),
+            ChangePasswordError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for ChangePasswordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangePasswordError::UserNotFound() => write!(f, "User not found"),
+            ChangePasswordError::InvalidCurrentPassword() => {
+                write!(f, "The current password is incorrect")
+            }
+            ChangePasswordError::WeakPassword(invalid_param) => {
+                write!(f, "{} {}", invalid_param.name, invalid_param.reason)
+            }
+            ChangePasswordError::PasswordHashing(e) => {
+                write!(f, "Failed to hash password: {}", e)
+            }
+            ChangePasswordError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct ChangePasswordUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    session_repository: Arc<dyn SessionRepository + Send + Sync>,
+}
+
+impl ChangePasswordUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        session_repository: Arc<dyn SessionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+        }
+    }
+
+    pub async fn execute(&self, request: ChangePasswordRequest) -> Result<(), ChangePasswordError> {
+        let user = self
+            .user_repository
+            .get_user_by_id(request.user_id.clone())
+            .await?
+            .ok_or(ChangePasswordError::UserNotFound())?;
+
+        if !verify_password(&request.current_password, user.password()) {
+            return Err(ChangePasswordError::InvalidCurrentPassword());
+        }
+
+        if let Some(invalid_param) = validate_password("new_password", &request.new_password) {
+            return Err(ChangePasswordError::WeakPassword(invalid_param));
+        }
+
+        let password_hash = hash_password(&request.new_password)
+            .map_err(|e| ChangePasswordError::PasswordHashing(e.to_string()))?;
+
+        self.user_repository
+            .update_password(request.user_id.clone(), password_hash)
+            .await?;
+
+        // Every session, including the caller's, was opened with the old
+        // password; the client signs in again with the new one.
+        self.session_repository
+            .revoke_all_for_user(request.user_id)
+            .await
+            .map_err(ChangePasswordError::Repository)?;
+        Ok(())
+    }
+}
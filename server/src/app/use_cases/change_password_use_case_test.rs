@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+
+    use crate::app::entities::{session::Session, user::User};
+    use crate::app::repositories::mock_session_repository::MockSessionRepository;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::use_cases::change_password_use_case::{
+        ChangePasswordError, ChangePasswordRequest, ChangePasswordUseCase,
+    };
+    use crate::app::use_cases::password_hashing::verify_password;
+
+    struct Fixture {
+        user_id: String,
+        user_repo: Arc<MockUserRepository>,
+        session_repo: Arc<MockSessionRepository>,
+        use_case: ChangePasswordUseCase,
+    }
+
+    fn setup() -> Fixture {
+        let user_repo = Arc::new(MockUserRepository::new());
+        user_repo.add_user(User::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "old-password".to_string(),
+        ));
+        let user_id = user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+
+        let session_repo = Arc::new(MockSessionRepository::new());
+        session_repo.add_session(Session::new(
+            "session-1".to_string(),
+            user_id.clone(),
+            "hash".to_string(),
+            Utc::now() + Duration::days(1),
+        ));
+
+        let use_case = ChangePasswordUseCase::new(user_repo.clone(), session_repo.clone());
+        Fixture {
+            user_id,
+            user_repo,
+            session_repo,
+            use_case,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_update_password_and_revoke_sessions() {
+        // Arrange
+        let fixture = setup();
+        let request = ChangePasswordRequest::new(
+            fixture.user_id.clone(),
+            "old-password".to_string(),
+            "new-password".to_string(),
+        );
+
+        // Act
+        let result = fixture.use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let user = fixture
+            .user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap();
+        assert!(verify_password("new-password", user.password()));
+        let session = fixture.session_repo.find_session("session-1").unwrap();
+        assert!(!session.is_active(Utc::now()));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_wrong_current_password_should_keep_sessions() {
+        // Arrange
+        let fixture = setup();
+        let request = ChangePasswordRequest::new(
+            fixture.user_id.clone(),
+            "not-my-password".to_string(),
+            "new-password".to_string(),
+        );
+
+        // Act
+        let result = fixture.use_case.execute(request).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ChangePasswordError::InvalidCurrentPassword())
+        ));
+        let session = fixture.session_repo.find_session("session-1").unwrap();
+        assert!(session.is_active(Utc::now()));
+    }
+}
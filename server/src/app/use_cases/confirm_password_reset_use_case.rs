@@ -0,0 +1,130 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::app::{
+    errors::app_error::{AppError, InvalidParam},
+    repositories::{
+        password_reset_repository::PasswordResetRepository, repository_error::RepositoryError,
+    },
+    use_cases::{
+        opaque_tokens::hash_opaque_token, password_hashing::hash_password,
+        user_validation::validate_password,
+    },
+};
+
+pub struct ConfirmPasswordResetRequest {
+    token: String,
+    new_password: String,
+}
+
+impl ConfirmPasswordResetRequest {
+    pub fn new(token: String, new_password: String) -> Self {
+        Self {
+            token,
+            new_password,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfirmPasswordResetError {
+    InvalidToken(),
+    WeakPassword(InvalidParam),
+    PasswordHashing(String),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for ConfirmPasswordResetError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => ConfirmPasswordResetError::InvalidToken(),
+            error => ConfirmPasswordResetError::Repository(error),
+        }
+    }
+}
+
+impl From<ConfirmPasswordResetError> for AppError {
+    fn from(error: ConfirmPasswordResetError) -> Self {
+        match error {
+            ConfirmPasswordResetError::InvalidToken() => {
+                AppError::bad_request("invalid_reset_token", error.to_string())
+            }
+            ConfirmPasswordResetError::WeakPassword(invalid_param) => {
+                AppError::unprocessable("validation_failed", "One or more fields are invalid")
+                    .with_invalid_params(vec![invalid_param])
+            }
+            ConfirmPasswordResetError::PasswordHashing(_) => AppError::internal(error.to_string()),
+            ConfirmPasswordResetError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfirmPasswordResetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfirmPasswordResetError::InvalidToken() => {
+                write!(
+                    f,
+                    "Password reset token is invalid, expired or already used"
+                )
+            }
+            ConfirmPasswordResetError::WeakPassword(invalid_param) => {
+                write!(f, "{} {}", invalid_param.name, invalid_param.reason)
+            }
+            ConfirmPasswordResetError::PasswordHashing(e) => {
+                write!(f, "Failed to hash password: {}", e)
+            }
+            ConfirmPasswordResetError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct ConfirmPasswordResetUseCase {
+    password_reset_repository: Arc<dyn PasswordResetRepository + Send + Sync>,
+}
+
+impl ConfirmPasswordResetUseCase {
+    pub fn new(password_reset_repository: Arc<dyn PasswordResetRepository + Send + Sync>) -> Self {
+        Self {
+            password_reset_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: ConfirmPasswordResetRequest,
+    ) -> Result<(), ConfirmPasswordResetError> {
+        // Checked before the token is looked up so a weak choice does not
+        // burn the link the user was sent.
+        if let Some(invalid_param) = validate_password("new_password", &request.new_password) {
+            return Err(ConfirmPasswordResetError::WeakPassword(invalid_param));
+        }
+
+        let token = self
+            .password_reset_repository
+            .find_by_token_hash(hash_opaque_token(&request.token))
+            .await?;
+
+        let token = match token {
+            Some(token) if token.is_usable(Utc::now()) => token,
+            _ => return Err(ConfirmPasswordResetError::InvalidToken()),
+        };
+
+        let password_hash = hash_password(&request.new_password)
+            .map_err(|e| ConfirmPasswordResetError::PasswordHashing(e.to_string()))?;
+
+        // Burning the token, storing the password and revoking every session
+        // (whoever knew the old password must not keep one) happen together,
+        // so a failure halfway cannot leave a spent link or a live session.
+        self.password_reset_repository
+            .reset_password(
+                token.id().to_string(),
+                token.user_id().to_string(),
+                password_hash,
+            )
+            .await?;
+        Ok(())
+    }
+}
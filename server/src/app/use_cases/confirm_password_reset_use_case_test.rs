@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use argon2::{Argon2, PasswordHash, PasswordVerifier};
+    use chrono::{Duration, Utc};
+
+    use crate::app::entities::password_reset_token::PasswordResetToken;
+    use crate::app::repositories::mock_password_reset_repository::MockPasswordResetRepository;
+    use crate::app::use_cases::confirm_password_reset_use_case::{
+        ConfirmPasswordResetError, ConfirmPasswordResetRequest, ConfirmPasswordResetUseCase,
+    };
+    use crate::app::use_cases::opaque_tokens::hash_opaque_token;
+
+    const USER_ID: &str = "user-1";
+
+    struct Fixture {
+        reset_repo: Arc<MockPasswordResetRepository>,
+        use_case: ConfirmPasswordResetUseCase,
+    }
+
+    fn setup(expires_in: Duration) -> Fixture {
+        let reset_repo = Arc::new(MockPasswordResetRepository::new());
+        reset_repo.add_token(PasswordResetToken::new(
+            "token-1".to_string(),
+            USER_ID.to_string(),
+            hash_opaque_token("reset-token"),
+            Utc::now() + expires_in,
+        ));
+
+        let use_case = ConfirmPasswordResetUseCase::new(reset_repo.clone());
+        Fixture {
+            reset_repo,
+            use_case,
+        }
+    }
+
+    fn request() -> ConfirmPasswordResetRequest {
+        ConfirmPasswordResetRequest::new("reset-token".to_string(), "new-password".to_string())
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_reset_password_with_new_hash() {
+        // Arrange
+        let fixture = setup(Duration::hours(1));
+
+        // Act
+        let result = fixture.use_case.execute(request()).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let (user_id, password_hash) = fixture.reset_repo.last_password_reset().unwrap();
+        assert_eq!(user_id, USER_ID);
+        let hash = PasswordHash::new(&password_hash).unwrap();
+        assert!(
+            Argon2::default()
+                .verify_password(b"new-password", &hash)
+                .is_ok()
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_burn_other_outstanding_tokens() {
+        // Arrange
+        let fixture = setup(Duration::hours(1));
+        fixture.reset_repo.add_token(PasswordResetToken::new(
+            "token-2".to_string(),
+            USER_ID.to_string(),
+            hash_opaque_token("older-token"),
+            Utc::now() + Duration::hours(1),
+        ));
+
+        // Act
+        fixture.use_case.execute(request()).await.unwrap();
+
+        // Assert
+        let older = fixture.reset_repo.find_token("token-2").unwrap();
+        assert!(older.used_at().is_some());
+        let older_request = ConfirmPasswordResetRequest::new(
+            "older-token".to_string(),
+            "other-password".to_string(),
+        );
+        assert!(matches!(
+            fixture.use_case.execute(older_request).await,
+            Err(ConfirmPasswordResetError::InvalidToken())
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_twice_with_same_token_should_return_error() {
+        // Arrange
+        let fixture = setup(Duration::hours(1));
+        fixture.use_case.execute(request()).await.unwrap();
+
+        // Act
+        let result = fixture.use_case.execute(request()).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfirmPasswordResetError::InvalidToken())
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_expired_token_should_return_error() {
+        // Arrange
+        let fixture = setup(Duration::seconds(-1));
+
+        // Act
+        let result = fixture.use_case.execute(request()).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfirmPasswordResetError::InvalidToken())
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_weak_password_should_keep_token_usable() {
+        // Arrange
+        let fixture = setup(Duration::hours(1));
+        let weak = ConfirmPasswordResetRequest::new("reset-token".to_string(), "short".to_string());
+
+        // Act
+        let result = fixture.use_case.execute(weak).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfirmPasswordResetError::WeakPassword(_))
+        ));
+        assert!(fixture.reset_repo.last_password_reset().is_none());
+        assert!(fixture.use_case.execute(request()).await.is_ok());
+    }
+}
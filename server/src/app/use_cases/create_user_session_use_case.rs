@@ -1,18 +1,21 @@
 use core::fmt;
 use std::sync::Arc;
 
-use argon2::{Argon2, PasswordVerifier};
 use chrono::Utc;
-use jsonwebtoken::{Algorithm, EncodingKey, Header, encode, errors::Error};
-use serde::{Deserialize, Serialize};
 
-use crate::app::{entities::user::User, repositories::user_repository::UserRepository};
-
-#[derive(Debug, Deserialize, Serialize)]
-struct Claims {
-    sub: String,
-    exp: usize,
-}
+use crate::app::{
+    entities::session::Session,
+    errors::app_error::AppError,
+    repositories::{
+        repository_error::RepositoryError, session_repository::SessionRepository,
+        user_repository::UserRepository,
+    },
+    use_cases::{
+        password_hashing::verify_password,
+        session_tokens::{generate_refresh_token, hash_refresh_token, issue_access_token},
+    },
+};
+use crate::settings::JwtSettings;
 
 pub struct CreateUserSessionRequest {
     pub email: String,
@@ -26,18 +29,44 @@ impl CreateUserSessionRequest {
 }
 
 pub struct CreateUserSessionResponse {
-    pub token: String,
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
 }
 
 impl CreateUserSessionResponse {
-    pub fn new(token: String) -> Self {
-        Self { token }
+    pub fn new(access_token: String, refresh_token: String, expires_in: i64) -> Self {
+        Self {
+            access_token,
+            refresh_token,
+            expires_in,
+        }
     }
 }
 
 #[derive(Debug)]
 pub enum CreateUserSessionError {
     InvalidCredentials(String),
+    TokenIssuing(String),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for CreateUserSessionError {
+    fn from(error: RepositoryError) -> Self {
+        CreateUserSessionError::Repository(error)
+    }
+}
+
+impl From<CreateUserSessionError> for AppError {
+    fn from(error: CreateUserSessionError) -> Self {
+        match error {
+            CreateUserSessionError::InvalidCredentials(_) => {
+                AppError::unauthorized("invalid_credentials", "Invalid email or password")
+            }
+            CreateUserSessionError::TokenIssuing(_) => AppError::internal(error.to_string()),
+            CreateUserSessionError::Repository(e) => e.into(),
+        }
+    }
 }
 
 impl fmt::Display for CreateUserSessionError {
@@ -46,74 +75,76 @@ impl fmt::Display for CreateUserSessionError {
             CreateUserSessionError::InvalidCredentials(e) => {
                 write!(f, "Invalid credentials: {}", e)
             }
+            CreateUserSessionError::TokenIssuing(e) => write!(f, "Failed to issue token: {}", e),
+            CreateUserSessionError::Repository(e) => write!(f, "{}", e),
         }
     }
 }
 
 pub struct CreateUserSessionUseCase {
     user_repository: Arc<dyn UserRepository + Send + Sync>,
+    session_repository: Arc<dyn SessionRepository + Send + Sync>,
+    jwt_settings: JwtSettings,
 }
 
 impl CreateUserSessionUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository + Send + Sync>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        session_repository: Arc<dyn SessionRepository + Send + Sync>,
+        jwt_settings: JwtSettings,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+            jwt_settings,
+        }
     }
 
     pub async fn execute(
         &self,
         request: CreateUserSessionRequest,
     ) -> Result<CreateUserSessionResponse, CreateUserSessionError> {
-        let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+        let user = self
+            .user_repository
+            .get_user_by_email(request.email)
+            .await?;
 
-        let user = self.user_repository.get_user_by_email(request.email).await;
-
-        if let Some(user) = user {
-            let does_password_match = argon2::PasswordHash::new(&user.password())
-                .and_then(|hash| {
-                    Argon2::default().verify_password(request.password.as_bytes(), &hash)
-                })
-                .is_ok();
-
-            if !does_password_match {
-                return Err(CreateUserSessionError::InvalidCredentials(
-                    "Invalid credentials".to_string(),
-                ));
-            }
+        let Some(user) = user else {
+            return Err(CreateUserSessionError::InvalidCredentials(
+                "Invalid credentials".to_string(),
+            ));
+        };
 
-            let expiration = Utc::now()
-                .checked_add_signed(chrono::Duration::seconds(100000))
-                .expect("valid timestamp")
-                .timestamp();
-
-            if let Some(user_id) = user.id() {
-                let claims = Claims {
-                    sub: user_id.to_string(),
-                    exp: expiration as usize,
-                };
-                let header = Header::new(Algorithm::HS512);
-                let token = encode(
-                    &header,
-                    &claims,
-                    &EncodingKey::from_secret(jwt_secret.as_ref()),
-                )
-                .map_err(Error::from);
-
-                if let Ok(token) = token {
-                    return Ok(CreateUserSessionResponse::new(token));
-                } else {
-                    Err(CreateUserSessionError::InvalidCredentials(
-                        "Invalid Credential".to_string(),
-                    ))
-                }
-            } else {
-                return Err(CreateUserSessionError::InvalidCredentials(
-                    "Invalid credentials".to_string(),
-                ));
-            }
-        } else {
+        if !verify_password(&request.password, user.password()) {
             return Err(CreateUserSessionError::InvalidCredentials(
                 "Invalid credentials".to_string(),
             ));
         }
+
+        let Some(user_id) = user.id() else {
+            return Err(CreateUserSessionError::InvalidCredentials(
+                "Invalid credentials".to_string(),
+            ));
+        };
+
+        let refresh_token = generate_refresh_token();
+        let session = Session::new(
+            uuid::Uuid::new_v4().to_string(),
+            user_id.to_string(),
+            hash_refresh_token(&refresh_token),
+            Utc::now() + self.jwt_settings.refresh_token_ttl(),
+        );
+
+        let (access_token, expires_in) =
+            issue_access_token(&self.jwt_settings, user_id, session.id())
+                .map_err(|e| CreateUserSessionError::TokenIssuing(e.to_string()))?;
+
+        self.session_repository.create(session).await?;
+
+        Ok(CreateUserSessionResponse::new(
+            access_token,
+            refresh_token,
+            expires_in,
+        ))
     }
 }
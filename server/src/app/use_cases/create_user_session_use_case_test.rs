@@ -8,21 +8,24 @@ mod tests {
     };
 
     use crate::app::entities::user::User;
+    use crate::app::repositories::mock_session_repository::MockSessionRepository;
     use crate::app::repositories::mock_user_repository::MockUserRepository;
     use crate::app::use_cases::create_user_session_use_case::{
         CreateUserSessionError, CreateUserSessionRequest, CreateUserSessionUseCase,
     };
+    use crate::app::use_cases::session_tokens::hash_refresh_token;
+    use crate::settings::JwtSettings;
 
-    fn setup() {
-        unsafe {
-            std::env::set_var("JWT_SECRET", "test_secret_key_for_sessions");
+    fn jwt_settings() -> JwtSettings {
+        JwtSettings {
+            secret: "test_secret_key_for_sessions".to_string(),
+            ..JwtSettings::default()
         }
     }
 
     #[actix_web::test]
     async fn test_execute_with_valid_credentials_should_return_token() {
         // Arrange
-        setup();
         let mock_repo: Arc<MockUserRepository> = Arc::new(MockUserRepository::new());
         let email = "test@example.com".to_string();
         let password = "password123".to_string();
@@ -30,7 +33,9 @@ mod tests {
         let user = User::new(email.clone(), "testuser".to_string(), password.clone());
         mock_repo.add_user(user);
 
-        let use_case = CreateUserSessionUseCase::new(mock_repo.clone());
+        let session_repo = Arc::new(MockSessionRepository::new());
+        let use_case =
+            CreateUserSessionUseCase::new(mock_repo.clone(), session_repo.clone(), jwt_settings());
         let request = CreateUserSessionRequest::new(email.clone(), password);
 
         // Act
@@ -38,20 +43,30 @@ mod tests {
 
         // Assert
         let response = result.expect("Expected a valid token response");
-        assert!(!response.token.is_empty());
+        assert!(!response.access_token.is_empty());
+        assert!(!response.refresh_token.is_empty());
+        let sessions = session_repo.sessions.lock().unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(
+            sessions[0].refresh_token_hash(),
+            hash_refresh_token(&response.refresh_token)
+        );
     }
 
     #[actix_web::test]
     async fn test_execute_with_invalid_password_should_return_error() {
         // Arrange
-        setup();
         let mock_repo = Arc::new(MockUserRepository::new());
         let email = "test@example.com".to_string();
         let password = "password123".to_string();
         let user = User::new(email.clone(), "testuser".to_string(), password.clone());
         mock_repo.add_user(user);
 
-        let use_case = CreateUserSessionUseCase::new(mock_repo.clone());
+        let use_case = CreateUserSessionUseCase::new(
+            mock_repo.clone(),
+            Arc::new(MockSessionRepository::new()),
+            jwt_settings(),
+        );
         let request = CreateUserSessionRequest::new(email.clone(), "wrong_password".to_string());
 
         // Act
@@ -63,15 +78,19 @@ mod tests {
             CreateUserSessionError::InvalidCredentials(msg) => {
                 assert_eq!(msg, "Invalid credentials");
             }
+            other => panic!("Expected InvalidCredentials, got {:?}", other),
         }
     }
 
     #[actix_web::test]
     async fn test_execute_with_non_existent_user_should_return_error() {
         // Arrange
-        setup();
         let mock_repo = Arc::new(MockUserRepository::new());
-        let use_case = CreateUserSessionUseCase::new(mock_repo.clone());
+        let use_case = CreateUserSessionUseCase::new(
+            mock_repo.clone(),
+            Arc::new(MockSessionRepository::new()),
+            jwt_settings(),
+        );
         let request = CreateUserSessionRequest::new(
             "nonexistent@example.com".to_string(),
             "password".to_string(),
@@ -86,13 +105,13 @@ mod tests {
             CreateUserSessionError::InvalidCredentials(msg) => {
                 assert_eq!(msg, "Invalid credentials");
             }
+            other => panic!("Expected InvalidCredentials, got {:?}", other),
         }
     }
 
     #[actix_web::test]
     async fn test_execute_with_user_without_id_should_return_error() {
         // Arrange
-        setup();
         let mock_repo = Arc::new(MockUserRepository::new());
         let email = "test@example.com".to_string();
         let password = "password123".to_string();
@@ -109,7 +128,11 @@ mod tests {
 
         mock_repo.users.lock().unwrap().push(user);
 
-        let use_case = CreateUserSessionUseCase::new(mock_repo.clone());
+        let use_case = CreateUserSessionUseCase::new(
+            mock_repo.clone(),
+            Arc::new(MockSessionRepository::new()),
+            jwt_settings(),
+        );
         let request = CreateUserSessionRequest::new(email.clone(), password.clone());
 
         // Act
@@ -121,6 +144,7 @@ mod tests {
             CreateUserSessionError::InvalidCredentials(msg) => {
                 assert_eq!(msg, "Invalid credentials");
             }
+            other => panic!("Expected InvalidCredentials, got {:?}", other),
         }
     }
 }
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, user_repository::UserRepository},
+    storage::object_storage::ObjectStorage,
+    use_cases::password_hashing::verify_password,
+};
+
+pub struct DeleteAccountRequest {
+    user_id: String,
+    password: String,
+}
+
+impl DeleteAccountRequest {
+    pub fn new(user_id: String, password: String) -> Self {
+        Self { user_id, password }
+    }
+}
+
+#[derive(Debug)]
+pub enum DeleteAccountError {
+    UserNotFound(),
+    InvalidPassword(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for DeleteAccountError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => DeleteAccountError::UserNotFound(),
+            error => DeleteAccountError::Repository(error),
+        }
+    }
+}
+
+impl From<DeleteAccountError> for AppError {
+    fn from(error: DeleteAccountError) -> Self {
+        match error {
+            DeleteAccountError::UserNotFound() => {
+                AppError::not_found("user_not_found", error.to_string())
+            }
+            DeleteAccountError::InvalidPassword() => {
+                AppError::forbidden("invalid_current_password", error.to_string())
+            }
+            DeleteAccountError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for DeleteAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeleteAccountError::UserNotFound() => write!(f, "User not found"),
+            DeleteAccountError::InvalidPassword() => {
+                write!(f, "The current password is incorrect")
+            }
+            DeleteAccountError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct DeleteAccountUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+}
+
+impl DeleteAccountUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            object_storage,
+        }
+    }
+
+    pub async fn execute(&self, request: DeleteAccountRequest) -> Result<(), DeleteAccountError> {
+        let user = self
+            .user_repository
+            .get_user_by_id(request.user_id.clone())
+            .await?
+            .ok_or(DeleteAccountError::UserNotFound())?;
+
+        if !verify_password(&request.password, user.password()) {
+            return Err(DeleteAccountError::InvalidPassword());
+        }
+
+        let avatars = self
+            .user_repository
+            .list_avatars(request.user_id.clone())
+            .await?;
+
+        self.user_repository
+            .delete_user(request.user_id.clone())
+            .await?;
+
+        // The account is gone at this point; a file that fails to delete is
+        // only logged so the user is not told their deletion failed.
+        for avatar in avatars {
+            let keys = std::iter::once(avatar.file_key()).chain(avatar.thumbnail_file_key());
+            for key in keys {
+                if let Err(e) = self.object_storage.delete(key.to_string()).await {
+                    tracing::warn!(file_key = %key, error = %e, "failed to delete avatar");
+                }
+            }
+        }
+        // Every key issued to the user starts with their id, including
+        // uploads that were never confirmed and so are in no table.
+        let prefix = format!("{}-", request.user_id);
+        if let Err(e) = self.object_storage.delete_with_prefix(prefix.clone()).await {
+            tracing::warn!(prefix = %prefix, error = %e, "failed to delete uploads");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::app::entities::user::User;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::storage::mock_object_storage::MockObjectStorage;
+    use crate::app::use_cases::delete_account_use_case::{
+        DeleteAccountError, DeleteAccountRequest, DeleteAccountUseCase,
+    };
+
+    fn setup() -> (
+        String,
+        Arc<MockUserRepository>,
+        Arc<MockObjectStorage>,
+        DeleteAccountUseCase,
+    ) {
+        let user_repo = Arc::new(MockUserRepository::new());
+        user_repo.add_user(User::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+        ));
+        let user_id = user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+        let storage = Arc::new(MockObjectStorage::new());
+        let use_case = DeleteAccountUseCase::new(user_repo.clone(), storage.clone());
+        (user_id, user_repo, storage, use_case)
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_delete_user_and_stored_avatar() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let request = DeleteAccountRequest::new(user_id, "password123".to_string());
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(user_repo.find_user_by_email("test@example.com").is_none());
+        assert!(user_repo.avatars.lock().unwrap().is_empty());
+        assert_eq!(
+            *storage.deleted.lock().unwrap(),
+            vec!["file_key".to_string()]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_delete_unconfirmed_uploads() {
+        // Arrange
+        let (user_id, _, storage, use_case) = setup();
+        let upload_key = format!("{}-{}", user_id, uuid::Uuid::new_v4());
+        storage.insert(&upload_key, "image/png", vec![1]);
+        storage.insert("other-user-upload", "image/png", vec![1]);
+        let request = DeleteAccountRequest::new(user_id.clone(), "password123".to_string());
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let objects = storage.objects.lock().unwrap();
+        assert!(!objects.contains_key(&upload_key));
+        assert!(objects.contains_key("other-user-upload"));
+        assert_eq!(
+            *storage.deleted_prefixes.lock().unwrap(),
+            vec![format!("{}-", user_id)]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_wrong_password_should_keep_account() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let request = DeleteAccountRequest::new(user_id, "wrong-password".to_string());
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(matches!(result, Err(DeleteAccountError::InvalidPassword())));
+        assert!(user_repo.find_user_by_email("test@example.com").is_some());
+        assert!(storage.deleted.lock().unwrap().is_empty());
+        assert!(storage.deleted_prefixes.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
+
+pub struct DeleteTaskRequest {
+    user_id: String,
+    task_id: String,
+}
+
+impl DeleteTaskRequest {
+    pub fn new(user_id: String, task_id: String) -> Self {
+        Self { user_id, task_id }
+    }
+}
+
+#[derive(Debug)]
+pub enum DeleteTaskError {
+    TaskNotFound(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for DeleteTaskError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => DeleteTaskError::TaskNotFound(),
+            error => DeleteTaskError::Repository(error),
+        }
+    }
+}
+
+impl From<DeleteTaskError> for AppError {
+    fn from(error: DeleteTaskError) -> Self {
+        match error {
+            DeleteTaskError::TaskNotFound() => {
+                AppError::not_found("task_not_found", error.to_string())
+            }
+            DeleteTaskError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for DeleteTaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeleteTaskError::TaskNotFound() => {
+                write!(f, "Task not found in database")
+            }
+            DeleteTaskError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Moves a task to the trash. The task stays recoverable through
+/// `RestoreTaskUseCase` until it is purged.
+pub struct DeleteTaskUseCase {
+    task_repository: Arc<dyn TaskRepository + Send + Sync>,
+}
+
+impl DeleteTaskUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository + Send + Sync>) -> Self {
+        Self { task_repository }
+    }
+
+    pub async fn execute(&self, request: DeleteTaskRequest) -> Result<(), DeleteTaskError> {
+        let task = self
+            .task_repository
+            .find_by_id(request.task_id.clone())
+            .await?;
+
+        match task {
+            Some(task) if task.user_id() == request.user_id && !task.is_deleted() => {}
+            _ => return Err(DeleteTaskError::TaskNotFound()),
+        }
+
+        self.task_repository
+            .soft_delete(request.user_id, request.task_id)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::app::entities::task::Task;
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::use_cases::delete_task_use_case::{
+        DeleteTaskError, DeleteTaskRequest, DeleteTaskUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_execute_should_move_task_to_trash() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        let task_id = mock_repo.add_task(task);
+        let use_case = DeleteTaskUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(DeleteTaskRequest::new(
+                "user-1".to_string(),
+                task_id.clone(),
+            ))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        let task = mock_repo.find_task(&task_id).expect("task should be kept");
+        assert!(task.is_deleted());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_task_of_another_user_should_return_not_found() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        let task_id = mock_repo.add_task(task);
+        let use_case = DeleteTaskUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(DeleteTaskRequest::new(
+                "user-2".to_string(),
+                task_id.clone(),
+            ))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(DeleteTaskError::TaskNotFound())));
+        assert!(!mock_repo.find_task(&task_id).unwrap().is_deleted());
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::app::{
+    entities::{avatar::Avatar, task::Task, user::User},
+    errors::app_error::AppError,
+    repositories::{
+        repository_error::RepositoryError,
+        task_repository::{TaskListQuery, TaskRepository},
+        user_repository::UserRepository,
+    },
+};
+
+pub struct ExportUserDataRequest {
+    user_id: String,
+}
+
+impl ExportUserDataRequest {
+    pub fn new(user_id: String) -> Self {
+        Self { user_id }
+    }
+}
+
+/// Everything stored about a user: profile, every task (trashed ones
+/// included) and avatar metadata.
+pub struct ExportUserDataResponse {
+    pub user: User,
+    pub tasks: Vec<Task>,
+    pub avatars: Vec<Avatar>,
+    pub exported_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub enum ExportUserDataError {
+    UserNotFound(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for ExportUserDataError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => ExportUserDataError::UserNotFound(),
+            error => ExportUserDataError::Repository(error),
+        }
+    }
+}
+
+impl From<ExportUserDataError> for AppError {
+    fn from(error: ExportUserDataError) -> Self {
+        match error {
+            ExportUserDataError::UserNotFound() => {
+                AppError::not_found("user_not_found", error.to_string())
+            }
+            ExportUserDataError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for ExportUserDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportUserDataError::UserNotFound() => write!(f, "User not found"),
+            ExportUserDataError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct ExportUserDataUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    task_repository: Arc<dyn TaskRepository + Send + Sync>,
+}
+
+impl ExportUserDataUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        task_repository: Arc<dyn TaskRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            task_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: ExportUserDataRequest,
+    ) -> Result<ExportUserDataResponse, ExportUserDataError> {
+        let user = self
+            .user_repository
+            .get_user_by_id(request.user_id.clone())
+            .await?
+            .ok_or(ExportUserDataError::UserNotFound())?;
+
+        let query = TaskListQuery {
+            limit: i64::MAX,
+            ..TaskListQuery::default()
+        };
+        let mut tasks = self
+            .task_repository
+            .list_all(request.user_id.clone(), query)
+            .await?;
+        tasks.extend(
+            self.task_repository
+                .list_trash(request.user_id.clone())
+                .await?,
+        );
+
+        let avatars = self.user_repository.list_avatars(request.user_id).await?;
+
+        Ok(ExportUserDataResponse {
+            user,
+            tasks,
+            avatars,
+            exported_at: Utc::now(),
+        })
+    }
+}
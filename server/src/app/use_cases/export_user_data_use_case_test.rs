@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::app::entities::{task::Task, user::User};
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::use_cases::export_user_data_use_case::{
+        ExportUserDataRequest, ExportUserDataUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_execute_should_include_active_and_trashed_tasks() {
+        // Arrange
+        let user_repo = Arc::new(MockUserRepository::new());
+        user_repo.add_user(User::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+        ));
+        let user_id = user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+
+        let task_repo = Arc::new(MockTaskRepository::new());
+        task_repo.add_task(Task::new(user_id.clone(), "active".to_string(), Utc::now()));
+        let mut trashed = Task::new(user_id.clone(), "trashed".to_string(), Utc::now());
+        trashed.set_deleted_at(Some(Utc::now()));
+        task_repo.add_task(trashed);
+        task_repo.add_task(Task::new(
+            "someone-else".to_string(),
+            "not mine".to_string(),
+            Utc::now(),
+        ));
+
+        let use_case = ExportUserDataUseCase::new(user_repo, task_repo);
+
+        // Act
+        let response = use_case
+            .execute(ExportUserDataRequest::new(user_id))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.user.email(), "test@example.com");
+        let mut contents: Vec<&str> = response.tasks.iter().map(|t| t.content()).collect();
+        contents.sort();
+        assert_eq!(contents, vec!["active", "trashed"]);
+        assert_eq!(response.avatars.len(), 1);
+    }
+}
@@ -1,8 +1,14 @@
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
-use serde::{Deserialize, Serialize};
+use crate::app::{
+    errors::app_error::AppError,
+    storage::object_storage::{ObjectStorage, StorageError},
+};
 
-use crate::env::get_env_var;
+/// How long a client has to start an avatar upload.
+pub const UPLOAD_URL_TTL: Duration = Duration::from_secs(600);
 
 pub struct GetSignedUrlUseCaseRequest {
     pub file_key: String,
@@ -31,105 +37,59 @@ impl GetSignedUrlUseCaseResponse {
 
 #[derive(Debug)]
 pub enum GetSignedUrlError {
-    RequestFailed(reqwest::Error),
-    InvalidResponse(reqwest::Error),
-    ApiError {
-        status: reqwest::StatusCode,
-        body: String,
-    },
-    MissingEnvVar(String),
+    Storage(StorageError),
 }
 
-impl fmt::Display for GetSignedUrlError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            GetSignedUrlError::RequestFailed(e) => {
-                write!(f, "Request to signed URL API failed: {}", e)
-            }
-            GetSignedUrlError::InvalidResponse(e) => {
-                write!(f, "Invalid response from signed URL API: {}", e)
-            }
-            GetSignedUrlError::ApiError { status, body } => {
-                write!(f, "Signed URL API returned error: {} - {}", status, body)
+impl From<StorageError> for GetSignedUrlError {
+    fn from(error: StorageError) -> Self {
+        GetSignedUrlError::Storage(error)
+    }
+}
+
+impl From<GetSignedUrlError> for AppError {
+    fn from(error: GetSignedUrlError) -> Self {
+        match error {
+            GetSignedUrlError::Storage(_) => {
+                AppError::bad_gateway("signed_url_unavailable", error.to_string())
             }
-            GetSignedUrlError::MissingEnvVar(e) => write!(f, "Missing environment variable: {}", e),
         }
     }
 }
 
-impl std::error::Error for GetSignedUrlError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl fmt::Display for GetSignedUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            GetSignedUrlError::RequestFailed(e) | GetSignedUrlError::InvalidResponse(e) => Some(e),
-            _ => None,
+            GetSignedUrlError::Storage(e) => write!(f, "Failed to sign upload URL: {}", e),
         }
     }
 }
 
-#[derive(Serialize)]
-pub struct OutgoingPayload {
-    file_key: String,
-    content_type: String,
-}
-
-impl OutgoingPayload {
-    pub fn new(file_key: String, content_type: String) -> Self {
-        Self {
-            file_key,
-            content_type,
+impl std::error::Error for GetSignedUrlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GetSignedUrlError::Storage(e) => Some(e),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct IncomingPayload {
-    pub url: String,
+pub struct GetSignedUrlUseCase {
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
 }
 
-pub struct GetSignedUrlUseCase {}
-
 impl GetSignedUrlUseCase {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(object_storage: Arc<dyn ObjectStorage + Send + Sync>) -> Self {
+        Self { object_storage }
     }
 
     pub async fn execute(
         &self,
         request: GetSignedUrlUseCaseRequest,
     ) -> Result<GetSignedUrlUseCaseResponse, GetSignedUrlError> {
-        let api_url = get_env_var("SIGNED_URL_API").ok_or_else(|| {
-            GetSignedUrlError::MissingEnvVar("SIGNED_URL_API must be set".to_string())
-        })?;
-        println!("API URL, {:?}", api_url);
-        let client = reqwest::Client::new();
-        let payload = OutgoingPayload::new(request.file_key, request.mime_type);
-        let payload = serde_json::json!({
-            "fileKey": payload.file_key.clone(),
-            "contentType": payload.content_type
-        });
-
-        let result = client
-            .post(format!("{}/uploads", api_url))
-            .json(&payload)
-            .send()
-            .await
-            .map_err(GetSignedUrlError::RequestFailed)?;
-
-        if !result.status().is_success() {
-            let status = result.status();
-            let body = result
-                .text()
-                .await
-                .unwrap_or_else(|_| "Could not retrieve error body".to_string());
-            return Err(GetSignedUrlError::ApiError { status, body });
-        }
-
-        let signed_url: IncomingPayload = result
-            .json()
-            .await
-            .map_err(GetSignedUrlError::InvalidResponse)?;
+        let url = self
+            .object_storage
+            .presign_put(request.file_key, request.mime_type, UPLOAD_URL_TTL)
+            .await?;
 
-        let response = GetSignedUrlUseCaseResponse::new(signed_url.url);
-        Ok(response)
+        Ok(GetSignedUrlUseCaseResponse::new(url))
     }
 }
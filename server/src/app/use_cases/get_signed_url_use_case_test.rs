@@ -1,35 +1,17 @@
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
 
-    // This test needs to be run with `cargo test -- --test-threads=1`
-
+    use crate::app::storage::mock_object_storage::MockObjectStorage;
     use crate::app::use_cases::get_signed_url_use_case::{
-        GetSignedUrlError, GetSignedUrlUseCase, GetSignedUrlUseCaseRequest, IncomingPayload,
+        GetSignedUrlError, GetSignedUrlUseCase, GetSignedUrlUseCaseRequest,
     };
-    use mockito::Server;
 
     #[actix_web::test]
     async fn execute_should_return_signed_url_on_success() {
         // Arrange
-        let mut server = Server::new_async().await;
-        let url = server.url();
-        unsafe {
-            std::env::set_var("SIGNED_URL_API", &url);
-        }
-
-        let mock_response_body = IncomingPayload {
-            url: "http://signed.url/for/test".to_string(),
-        };
-
-        let mock = server
-            .mock("POST", "/uploads")
-            .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(serde_json::to_string(&mock_response_body).unwrap())
-            .create_async()
-            .await;
-
-        let use_case = GetSignedUrlUseCase::new();
+        let storage = Arc::new(MockObjectStorage::new());
+        let use_case = GetSignedUrlUseCase::new(storage);
         let request =
             GetSignedUrlUseCaseRequest::new("test-key".to_string(), "image/png".to_string());
 
@@ -37,28 +19,19 @@ mod tests {
         let result = use_case.execute(request).await;
 
         // Assert
-        mock.assert_async().await;
         let response = result.expect("execute should not fail");
-        assert_eq!(response.url, "http://signed.url/for/test");
+        assert_eq!(
+            response.url,
+            "mock://put/test-key?content_type=image/png&expires_in=600"
+        );
     }
 
     #[actix_web::test]
-    async fn execute_should_return_error_on_api_failure() {
+    async fn execute_should_return_error_on_storage_failure() {
         // Arrange
-        let mut server = Server::new_async().await;
-        let url = server.url();
-        unsafe {
-            std::env::set_var("SIGNED_URL_API", &url);
-        }
-
-        let mock = server
-            .mock("POST", "/uploads")
-            .with_status(500)
-            .with_body("Internal Server Error")
-            .create_async()
-            .await;
-
-        let use_case = GetSignedUrlUseCase::new();
+        let storage = Arc::new(MockObjectStorage::new());
+        storage.fail_with("bucket unreachable");
+        let use_case = GetSignedUrlUseCase::new(storage);
         let request =
             GetSignedUrlUseCaseRequest::new("test-key".to_string(), "image/png".to_string());
 
@@ -66,13 +39,9 @@ mod tests {
         let result = use_case.execute(request).await;
 
         // Assert
-        mock.assert_async().await;
         match result {
-            Err(GetSignedUrlError::ApiError { status, body }) => {
-                assert_eq!(status, 500);
-                assert_eq!(body, "Internal Server Error");
-            }
-            _ => panic!("Expected ApiError, got {:?}", result),
+            Err(GetSignedUrlError::Storage(e)) => assert_eq!(e.0, "bucket unreachable"),
+            _ => panic!("Expected Storage error, got {:?}", result),
         }
     }
 }
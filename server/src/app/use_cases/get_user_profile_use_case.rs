@@ -1,16 +1,19 @@
 use core::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
-use serde::{Deserialize, Serialize};
-
-use crate::{
-    app::{
-        entities::user::{self, User},
-        repositories::user_repository::{UserProfile, UserRepository},
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{
+        repository_error::RepositoryError,
+        user_repository::{UserProfile, UserRepository},
     },
-    env::get_env_var,
+    storage::object_storage::{ObjectStorage, StorageError},
 };
 
+/// How long the avatar URL in a profile response stays valid.
+pub const AVATAR_URL_TTL: Duration = Duration::from_secs(3600);
+
 pub struct GetUserProfileRequest {
     pub user_id: String,
 }
@@ -31,31 +34,40 @@ impl GetUserProfileResponse {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct IncomingPayload {
-    pub url: String,
+#[derive(Debug)]
+pub enum GetUserProfileError {
+    UserNotFound(String),
+    Storage(StorageError),
+    Repository(RepositoryError),
 }
 
-#[derive(Serialize, Debug)]
-pub struct OutgoingPayload {
-    file_key: String,
+impl From<StorageError> for GetUserProfileError {
+    fn from(error: StorageError) -> Self {
+        GetUserProfileError::Storage(error)
+    }
 }
 
-impl OutgoingPayload {
-    pub fn new(file_key: String) -> Self {
-        Self { file_key }
+impl From<RepositoryError> for GetUserProfileError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(e) => GetUserProfileError::UserNotFound(e),
+            error => GetUserProfileError::Repository(error),
+        }
     }
 }
 
-pub enum GetUserProfileError {
-    UserNotFound(String),
-    RequestFailed(reqwest::Error),
-    InvalidResponse(reqwest::Error),
-    ApiError {
-        status: reqwest::StatusCode,
-        body: String,
-    },
-    MissingEnvVar(String),
+impl From<GetUserProfileError> for AppError {
+    fn from(error: GetUserProfileError) -> Self {
+        match error {
+            GetUserProfileError::UserNotFound(_) => {
+                AppError::not_found("user_not_found", error.to_string())
+            }
+            GetUserProfileError::Repository(e) => e.into(),
+            GetUserProfileError::Storage(_) => {
+                AppError::bad_gateway("signed_url_unavailable", error.to_string())
+            }
+        }
+    }
 }
 
 impl fmt::Display for GetUserProfileError {
@@ -64,69 +76,56 @@ impl fmt::Display for GetUserProfileError {
             GetUserProfileError::UserNotFound(e) => {
                 write!(f, "User not found in database: {}", e)
             }
-            GetUserProfileError::RequestFailed(e) => {
-                write!(f, "Request to signed URL API failed: {}", e)
-            }
-            GetUserProfileError::InvalidResponse(e) => {
-                write!(f, "Invalid response from signed URL API: {}", e)
-            }
-            GetUserProfileError::ApiError { status, body } => {
-                write!(f, "Signed URL API returned error: {} - {}", status, body)
-            }
-            GetUserProfileError::MissingEnvVar(e) => {
-                write!(f, "Missing environment variable: {}", e)
+            GetUserProfileError::Storage(e) => {
+                write!(f, "Failed to sign avatar URL: {}", e)
             }
+            GetUserProfileError::Repository(e) => write!(f, "{}", e),
         }
     }
 }
 
 pub struct GetUserProfileUseCase {
     user_repository: Arc<dyn UserRepository + Send + Sync>,
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
 }
 
 impl GetUserProfileUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository + Send + Sync>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            object_storage,
+        }
     }
 
     pub async fn execute(
         &self,
         request: GetUserProfileRequest,
     ) -> Result<GetUserProfileResponse, GetUserProfileError> {
-        let user_profile = self.user_repository.get_user_profile(request.user_id).await;
-        let api_url = get_env_var("SIGNED_URL_API").ok_or_else(|| {
-            GetUserProfileError::MissingEnvVar("SIGNED_URL_API must be set".to_string())
-        })?;
-        let client = reqwest::Client::new();
-
-        match user_profile {
-            Some(mut user_profile) => {
-                let result = client
-                    .get(format!("{}/uploads/{}", api_url, user_profile.avatar_url))
-                    .send()
-                    .await
-                    .map_err(GetUserProfileError::RequestFailed)?;
-
-                if !result.status().is_success() {
-                    let status = result.status();
-                    let body = result
-                        .text()
-                        .await
-                        .unwrap_or_else(|_| "Could not retrieve error body".to_string());
-                    return Err(GetUserProfileError::ApiError { status, body });
-                }
-
-                let avatar_url: IncomingPayload = result
-                    .json()
-                    .await
-                    .map_err(GetUserProfileError::InvalidResponse)?;
-                println!("Avatar URL: {:?}", avatar_url);
-                user_profile.set_avatar_url(avatar_url.url);
-                Ok(GetUserProfileResponse::new(user_profile))
-            }
-            None => Err(GetUserProfileError::UserNotFound(
-                "User not found".to_string(),
-            )),
-        }
+        let mut user_profile = self
+            .user_repository
+            .get_user_profile(request.user_id)
+            .await?
+            .ok_or_else(|| GetUserProfileError::UserNotFound("User not found".to_string()))?;
+
+        // The repository hands back object keys; clients get URLs.
+        let avatar_url = self
+            .object_storage
+            .presign_get(user_profile.avatar_url.clone(), AVATAR_URL_TTL)
+            .await?;
+        user_profile.set_avatar_url(avatar_url);
+
+        let thumbnail_url = match user_profile.thumbnail_url.clone() {
+            Some(thumbnail_key) => Some(
+                self.object_storage
+                    .presign_get(thumbnail_key, AVATAR_URL_TTL)
+                    .await?,
+            ),
+            None => None,
+        };
+        user_profile.set_thumbnail_url(thumbnail_url);
+        Ok(GetUserProfileResponse::new(user_profile))
     }
 }
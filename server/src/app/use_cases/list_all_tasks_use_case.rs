@@ -1,30 +1,123 @@
+use std::fmt;
 use std::sync::Arc;
 
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::app::{entities::task::Task, repositories::task_repository::TaskRepository};
+use crate::app::{
+    entities::{task::Task, task_status::TaskStatus},
+    errors::app_error::AppError,
+    repositories::{
+        repository_error::RepositoryError,
+        task_repository::{
+            SortDirection, TaskCursor, TaskListQuery, TaskRepository, TaskSortField,
+        },
+    },
+};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+pub const MAX_PAGE_SIZE: i64 = 100;
 
 #[derive(Serialize, Deserialize)]
 pub struct ListAllTasksResponse {
     pub tasks: Vec<Task>,
+    pub next_cursor: Option<String>,
 }
 
 impl ListAllTasksResponse {
-    pub fn new(tasks: Vec<Task>) -> Self {
-        Self { tasks }
+    pub fn new(tasks: Vec<Task>, next_cursor: Option<String>) -> Self {
+        Self { tasks, next_cursor }
     }
 }
 
 pub struct ListAllTasksRequest {
-    user_id: String,
+    pub user_id: String,
+    pub status: Option<TaskStatus>,
+    pub task_date_from: Option<DateTime<Utc>>,
+    pub task_date_to: Option<DateTime<Utc>>,
+    pub sort_by: TaskSortField,
+    pub direction: SortDirection,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
 }
 
 impl ListAllTasksRequest {
     pub fn new(user_id: String) -> Self {
-        Self { user_id }
+        Self {
+            user_id,
+            status: None,
+            task_date_from: None,
+            task_date_to: None,
+            sort_by: TaskSortField::default(),
+            direction: SortDirection::default(),
+            cursor: None,
+            limit: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ListAllTasksError {
+    InvalidCursor(),
+    InvalidDateRange(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for ListAllTasksError {
+    fn from(error: RepositoryError) -> Self {
+        ListAllTasksError::Repository(error)
     }
 }
 
+impl From<ListAllTasksError> for AppError {
+    fn from(error: ListAllTasksError) -> Self {
+        match error {
+            ListAllTasksError::InvalidCursor() => {
+                AppError::bad_request("invalid_cursor", error.to_string())
+            }
+            ListAllTasksError::InvalidDateRange() => {
+                AppError::bad_request("invalid_date_range", error.to_string())
+            }
+            ListAllTasksError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for ListAllTasksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListAllTasksError::InvalidCursor() => write!(f, "Invalid pagination cursor"),
+            ListAllTasksError::InvalidDateRange() => {
+                write!(f, "task_date_from must not be after task_date_to")
+            }
+            ListAllTasksError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+// Cursors are opaque to clients: "<sort value in microseconds>|<task id>"
+// encoded as URL-safe base64.
+fn encode_cursor(cursor: &TaskCursor) -> String {
+    URL_SAFE_NO_PAD.encode(format!(
+        "{}|{}",
+        cursor.sort_value.timestamp_micros(),
+        cursor.id
+    ))
+}
+
+fn decode_cursor(cursor: &str) -> Option<TaskCursor> {
+    let decoded = String::from_utf8(URL_SAFE_NO_PAD.decode(cursor).ok()?).ok()?;
+    let (micros, id) = decoded.split_once('|')?;
+    let sort_value = DateTime::from_timestamp_micros(micros.parse().ok()?)?;
+    uuid::Uuid::parse_str(id).ok()?;
+
+    Some(TaskCursor {
+        sort_value,
+        id: id.to_string(),
+    })
+}
+
 pub struct ListAllTasksUseCase {
     task_repository: Arc<dyn TaskRepository + Send + Sync>,
 }
@@ -34,8 +127,56 @@ impl ListAllTasksUseCase {
         Self { task_repository }
     }
 
-    pub async fn execute(&self, request: ListAllTasksRequest) -> ListAllTasksResponse {
-        let tasks = ListAllTasksResponse::new(self.task_repository.list_all(request.user_id).await);
-        tasks
+    pub async fn execute(
+        &self,
+        request: ListAllTasksRequest,
+    ) -> Result<ListAllTasksResponse, ListAllTasksError> {
+        if let (Some(from), Some(to)) = (request.task_date_from, request.task_date_to)
+            && from > to
+        {
+            return Err(ListAllTasksError::InvalidDateRange());
+        }
+
+        let after = match request.cursor {
+            Some(cursor) => Some(decode_cursor(&cursor).ok_or(ListAllTasksError::InvalidCursor())?),
+            None => None,
+        };
+        let limit = request
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+
+        // Ask for one extra task to know whether there is a next page.
+        let query = TaskListQuery {
+            status: request.status,
+            task_date_from: request.task_date_from,
+            task_date_to: request.task_date_to,
+            sort_by: request.sort_by,
+            direction: request.direction,
+            after,
+            limit: limit + 1,
+        };
+        let mut tasks = self
+            .task_repository
+            .list_all(request.user_id, query)
+            .await?;
+
+        let next_cursor = if tasks.len() as i64 > limit {
+            tasks.truncate(limit as usize);
+            tasks.last().map(|task| {
+                let sort_value = match request.sort_by {
+                    TaskSortField::TaskDate => *task.task_date(),
+                    TaskSortField::CreatedAt => *task.created_at(),
+                };
+                encode_cursor(&TaskCursor {
+                    sort_value,
+                    id: task.id().cloned().unwrap_or_default(),
+                })
+            })
+        } else {
+            None
+        };
+
+        Ok(ListAllTasksResponse::new(tasks, next_cursor))
     }
 }
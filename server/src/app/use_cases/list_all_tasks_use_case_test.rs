@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::app::entities::{task::Task, task_status::TaskStatus};
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::repositories::task_repository::SortDirection;
+    use crate::app::use_cases::list_all_tasks_use_case::{
+        ListAllTasksError, ListAllTasksRequest, ListAllTasksUseCase,
+    };
+
+    fn setup() -> Arc<MockTaskRepository> {
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let first_day = Utc.with_ymd_and_hms(2025, 10, 1, 9, 0, 0).unwrap();
+        for day in 0..5 {
+            let mut task = Task::new(
+                "user-1".to_string(),
+                format!("Task {}", day),
+                first_day + Duration::days(day),
+            );
+            if day % 2 == 0 {
+                task.set_status(TaskStatus::Completed);
+            }
+            mock_repo.add_task(task);
+        }
+        mock_repo.add_task(Task::new(
+            "user-2".to_string(),
+            "Someone else's task".to_string(),
+            first_day,
+        ));
+        mock_repo
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_page_through_tasks_with_cursor() {
+        // Arrange
+        let mock_repo = setup();
+        let use_case = ListAllTasksUseCase::new(mock_repo.clone());
+        let request = ListAllTasksRequest {
+            limit: Some(3),
+            ..ListAllTasksRequest::new("user-1".to_string())
+        };
+
+        // Act
+        let first_page = use_case.execute(request).await.unwrap();
+        let request = ListAllTasksRequest {
+            limit: Some(3),
+            cursor: first_page.next_cursor.clone(),
+            ..ListAllTasksRequest::new("user-1".to_string())
+        };
+        let second_page = use_case.execute(request).await.unwrap();
+
+        // Assert
+        let contents: Vec<&str> = first_page
+            .tasks
+            .iter()
+            .chain(second_page.tasks.iter())
+            .map(|t| t.content())
+            .collect();
+        assert_eq!(
+            contents,
+            vec!["Task 0", "Task 1", "Task 2", "Task 3", "Task 4"]
+        );
+        assert!(first_page.next_cursor.is_some());
+        assert!(second_page.next_cursor.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_filter_by_status_and_sort_descending() {
+        // Arrange
+        let mock_repo = setup();
+        let use_case = ListAllTasksUseCase::new(mock_repo.clone());
+        let request = ListAllTasksRequest {
+            status: Some(TaskStatus::Completed),
+            direction: SortDirection::Desc,
+            ..ListAllTasksRequest::new("user-1".to_string())
+        };
+
+        // Act
+        let response = use_case.execute(request).await.unwrap();
+
+        // Assert
+        let contents: Vec<&str> = response.tasks.iter().map(|t| t.content()).collect();
+        assert_eq!(contents, vec!["Task 4", "Task 2", "Task 0"]);
+        assert!(response.next_cursor.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_invalid_cursor_should_return_error() {
+        // Arrange
+        let mock_repo = setup();
+        let use_case = ListAllTasksUseCase::new(mock_repo.clone());
+        let request = ListAllTasksRequest {
+            cursor: Some("not-a-cursor".to_string()),
+            ..ListAllTasksRequest::new("user-1".to_string())
+        };
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(matches!(result, Err(ListAllTasksError::InvalidCursor())));
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::{
+    entities::task::Task,
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct ListTrashedTasksResponse {
+    pub tasks: Vec<Task>,
+}
+
+impl ListTrashedTasksResponse {
+    pub fn new(tasks: Vec<Task>) -> Self {
+        Self { tasks }
+    }
+}
+
+pub struct ListTrashedTasksRequest {
+    user_id: String,
+}
+
+impl ListTrashedTasksRequest {
+    pub fn new(user_id: String) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Debug)]
+pub enum ListTrashedTasksError {
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for ListTrashedTasksError {
+    fn from(error: RepositoryError) -> Self {
+        ListTrashedTasksError::Repository(error)
+    }
+}
+
+impl From<ListTrashedTasksError> for AppError {
+    fn from(error: ListTrashedTasksError) -> Self {
+        match error {
+            ListTrashedTasksError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for ListTrashedTasksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListTrashedTasksError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct ListTrashedTasksUseCase {
+    task_repository: Arc<dyn TaskRepository + Send + Sync>,
+}
+
+impl ListTrashedTasksUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository + Send + Sync>) -> Self {
+        Self { task_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        request: ListTrashedTasksRequest,
+    ) -> Result<ListTrashedTasksResponse, ListTrashedTasksError> {
+        let tasks = self.task_repository.list_trash(request.user_id).await?;
+        Ok(ListTrashedTasksResponse::new(tasks))
+    }
+}
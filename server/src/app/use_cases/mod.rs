@@ -1,10 +1,47 @@
+pub mod avatar_image;
+pub mod backfill_identicons_use_case;
+pub mod backfill_identicons_use_case_test;
+pub mod change_password_use_case;
+pub mod change_password_use_case_test;
+pub mod confirm_password_reset_use_case;
+pub mod confirm_password_reset_use_case_test;
 pub mod create_user_session_use_case;
 pub mod create_user_session_use_case_test;
+pub mod delete_account_use_case;
+pub mod delete_account_use_case_test;
+pub mod delete_task_use_case;
+pub mod delete_task_use_case_test;
+pub mod export_user_data_use_case;
+pub mod export_user_data_use_case_test;
 pub mod get_signed_url_use_case;
 pub mod get_signed_url_use_case_test;
 pub mod get_user_profile_use_case;
 pub mod list_all_tasks_use_case;
+pub mod list_all_tasks_use_case_test;
+pub mod list_trashed_tasks_use_case;
+pub mod opaque_tokens;
+pub mod password_hashing;
+pub mod purge_task_use_case;
+pub mod purge_task_use_case_test;
+pub mod refresh_session_use_case;
+pub mod refresh_session_use_case_test;
 pub mod register_user_use_case;
 pub mod register_user_use_case_test;
+pub mod request_password_reset_use_case;
+pub mod request_password_reset_use_case_test;
+pub mod restore_task_use_case;
+pub mod restore_task_use_case_test;
+pub mod revoke_all_sessions_use_case;
+pub mod revoke_all_sessions_use_case_test;
+pub mod revoke_session_use_case;
 pub mod save_task_use_case;
+pub mod session_tokens;
+pub mod update_avatar_use_case;
+pub mod update_avatar_use_case_test;
 pub mod update_task_status_use_case;
+pub mod update_task_status_use_case_test;
+pub mod update_task_use_case;
+pub mod update_task_use_case_test;
+pub mod update_user_profile_use_case;
+pub mod update_user_profile_use_case_test;
+pub mod user_validation;
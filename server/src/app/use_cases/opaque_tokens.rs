@@ -0,0 +1,14 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256};
+
+/// Random, URL safe token handed to a client. Only its hash is persisted.
+pub fn generate_opaque_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn hash_opaque_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
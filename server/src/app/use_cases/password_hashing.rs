@@ -0,0 +1,20 @@
+use argon2::{
+    Argon2, PasswordHash,
+    password_hash::{PasswordHasher, PasswordVerifier, SaltString, rand_core},
+};
+
+/// Hashes a password with the Argon2 setup shared by registration and every
+/// flow that changes a password.
+pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(rand_core::OsRng);
+    let password_hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(password_hash.to_string())
+}
+
+/// Checks a plain text password against a stored Argon2 hash. A malformed
+/// hash never matches.
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .and_then(|hash| Argon2::default().verify_password(password.as_bytes(), &hash))
+        .is_ok()
+}
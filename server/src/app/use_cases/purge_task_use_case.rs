@@ -0,0 +1,84 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
+
+pub struct PurgeTaskRequest {
+    user_id: String,
+    task_id: String,
+}
+
+impl PurgeTaskRequest {
+    pub fn new(user_id: String, task_id: String) -> Self {
+        Self { user_id, task_id }
+    }
+}
+
+#[derive(Debug)]
+pub enum PurgeTaskError {
+    TaskNotFound(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for PurgeTaskError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => PurgeTaskError::TaskNotFound(),
+            error => PurgeTaskError::Repository(error),
+        }
+    }
+}
+
+impl From<PurgeTaskError> for AppError {
+    fn from(error: PurgeTaskError) -> Self {
+        match error {
+            PurgeTaskError::TaskNotFound() => {
+                AppError::not_found("task_not_found", error.to_string())
+            }
+            PurgeTaskError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for PurgeTaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PurgeTaskError::TaskNotFound() => {
+                write!(f, "Task not found in trash")
+            }
+            PurgeTaskError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Permanently removes a task. Only tasks that are already in the trash
+/// can be purged.
+pub struct PurgeTaskUseCase {
+    task_repository: Arc<dyn TaskRepository + Send + Sync>,
+}
+
+impl PurgeTaskUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository + Send + Sync>) -> Self {
+        Self { task_repository }
+    }
+
+    pub async fn execute(&self, request: PurgeTaskRequest) -> Result<(), PurgeTaskError> {
+        let task = self
+            .task_repository
+            .find_by_id(request.task_id.clone())
+            .await?;
+
+        match task {
+            Some(task) if task.user_id() == request.user_id && task.is_deleted() => {}
+            _ => return Err(PurgeTaskError::TaskNotFound()),
+        }
+
+        self.task_repository
+            .purge(request.user_id, request.task_id)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::app::entities::task::Task;
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::use_cases::purge_task_use_case::{
+        PurgeTaskError, PurgeTaskRequest, PurgeTaskUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_execute_should_remove_trashed_task_permanently() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let mut task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        task.set_deleted_at(Some(Utc::now()));
+        let task_id = mock_repo.add_task(task);
+        let use_case = PurgeTaskUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(PurgeTaskRequest::new("user-1".to_string(), task_id.clone()))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(mock_repo.find_task(&task_id).is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_task_not_in_trash_should_keep_it() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        let task_id = mock_repo.add_task(task);
+        let use_case = PurgeTaskUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(PurgeTaskRequest::new("user-1".to_string(), task_id.clone()))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(PurgeTaskError::TaskNotFound())));
+        assert!(mock_repo.find_task(&task_id).is_some());
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, session_repository::SessionRepository},
+    use_cases::session_tokens::{
+        SessionTokens, generate_refresh_token, hash_refresh_token, issue_access_token,
+    },
+};
+use crate::settings::JwtSettings;
+
+pub struct RefreshSessionRequest {
+    refresh_token: String,
+}
+
+impl RefreshSessionRequest {
+    pub fn new(refresh_token: String) -> Self {
+        Self { refresh_token }
+    }
+}
+
+#[derive(Debug)]
+pub enum RefreshSessionError {
+    InvalidRefreshToken(),
+    RefreshTokenReused(),
+    TokenIssuing(String),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for RefreshSessionError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => RefreshSessionError::InvalidRefreshToken(),
+            error => RefreshSessionError::Repository(error),
+        }
+    }
+}
+
+impl From<RefreshSessionError> for AppError {
+    fn from(error: RefreshSessionError) -> Self {
+        match error {
+            RefreshSessionError::InvalidRefreshToken() => {
+                AppError::unauthorized("invalid_refresh_token", error.to_string())
+            }
+            RefreshSessionError::RefreshTokenReused() => {
+                AppError::unauthorized("refresh_token_reused", error.to_string())
+            }
+            RefreshSessionError::TokenIssuing(_) => AppError::internal(error.to_string()),
+            RefreshSessionError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for RefreshSessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefreshSessionError::InvalidRefreshToken() => {
+                write!(f, "Refresh token is invalid, expired or revoked")
+            }
+            RefreshSessionError::RefreshTokenReused() => {
+                write!(
+                    f,
+                    "Refresh token was already used, the session has been revoked"
+                )
+            }
+            RefreshSessionError::TokenIssuing(e) => write!(f, "Failed to issue token: {}", e),
+            RefreshSessionError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct RefreshSessionUseCase {
+    session_repository: Arc<dyn SessionRepository + Send + Sync>,
+    jwt_settings: JwtSettings,
+}
+
+impl RefreshSessionUseCase {
+    pub fn new(
+        session_repository: Arc<dyn SessionRepository + Send + Sync>,
+        jwt_settings: JwtSettings,
+    ) -> Self {
+        Self {
+            session_repository,
+            jwt_settings,
+        }
+    }
+
+    /// A token the session already rotated out means two parties hold it,
+    /// so the session is revoked for both.
+    async fn reject_unknown_token(&self, refresh_token_hash: String) -> RefreshSessionError {
+        let rotated_from = match self
+            .session_repository
+            .find_by_rotated_refresh_token_hash(refresh_token_hash)
+            .await
+        {
+            Ok(Some(session)) if session.revoked_at().is_none() => session,
+            Ok(_) => return RefreshSessionError::InvalidRefreshToken(),
+            Err(e) => return e.into(),
+        };
+        tracing::warn!(
+            session_id = rotated_from.id(),
+            "rotated refresh token reused, revoking the session"
+        );
+        match self
+            .session_repository
+            .revoke(rotated_from.id().to_string())
+            .await
+        {
+            Ok(()) => RefreshSessionError::RefreshTokenReused(),
+            Err(e) => e.into(),
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        request: RefreshSessionRequest,
+    ) -> Result<SessionTokens, RefreshSessionError> {
+        let current_hash = hash_refresh_token(&request.refresh_token);
+        let session = self
+            .session_repository
+            .find_by_refresh_token_hash(current_hash.clone())
+            .await?;
+
+        let session = match session {
+            Some(session) if session.is_active(Utc::now()) => session,
+            Some(_) => return Err(RefreshSessionError::InvalidRefreshToken()),
+            None => return Err(self.reject_unknown_token(current_hash).await),
+        };
+
+        let refresh_token = generate_refresh_token();
+        self.session_repository
+            .rotate(
+                session.id().to_string(),
+                current_hash,
+                hash_refresh_token(&refresh_token),
+                Utc::now() + self.jwt_settings.refresh_token_ttl(),
+            )
+            .await?;
+
+        let (access_token, expires_in) =
+            issue_access_token(&self.jwt_settings, session.user_id(), session.id())
+                .map_err(|e| RefreshSessionError::TokenIssuing(e.to_string()))?;
+
+        Ok(SessionTokens {
+            access_token,
+            refresh_token,
+            expires_in,
+        })
+    }
+}
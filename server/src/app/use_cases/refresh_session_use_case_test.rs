@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+
+    use crate::app::entities::session::Session;
+    use crate::app::repositories::mock_session_repository::MockSessionRepository;
+    use crate::app::use_cases::refresh_session_use_case::{
+        RefreshSessionError, RefreshSessionRequest, RefreshSessionUseCase,
+    };
+    use crate::app::use_cases::session_tokens::hash_refresh_token;
+    use crate::settings::JwtSettings;
+
+    fn jwt_settings() -> JwtSettings {
+        JwtSettings {
+            secret: "test_secret_key_for_sessions".to_string(),
+            ..JwtSettings::default()
+        }
+    }
+
+    fn setup(expires_in: Duration) -> Arc<MockSessionRepository> {
+        let mock_repo = Arc::new(MockSessionRepository::new());
+        mock_repo.add_session(Session::new(
+            "session-1".to_string(),
+            "user-1".to_string(),
+            hash_refresh_token("refresh-token"),
+            Utc::now() + expires_in,
+        ));
+        mock_repo
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_rotate_refresh_token() {
+        // Arrange
+        let mock_repo = setup(Duration::days(1));
+        let use_case = RefreshSessionUseCase::new(mock_repo.clone(), jwt_settings());
+
+        // Act
+        let result = use_case
+            .execute(RefreshSessionRequest::new("refresh-token".to_string()))
+            .await;
+
+        // Assert
+        let tokens = result.expect("Expected new session tokens");
+        assert!(!tokens.access_token.is_empty());
+        assert_ne!(tokens.refresh_token, "refresh-token");
+        let session = mock_repo.find_session("session-1").unwrap();
+        assert_eq!(
+            session.refresh_token_hash(),
+            hash_refresh_token(&tokens.refresh_token)
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_already_rotated_token_should_revoke_session() {
+        // Arrange
+        let mock_repo = setup(Duration::days(1));
+        let use_case = RefreshSessionUseCase::new(mock_repo.clone(), jwt_settings());
+        let current = use_case
+            .execute(RefreshSessionRequest::new("refresh-token".to_string()))
+            .await
+            .unwrap();
+
+        // Act
+        let reused = use_case
+            .execute(RefreshSessionRequest::new("refresh-token".to_string()))
+            .await;
+        let after_reuse = use_case
+            .execute(RefreshSessionRequest::new(current.refresh_token))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            reused,
+            Err(RefreshSessionError::RefreshTokenReused())
+        ));
+        assert!(
+            mock_repo
+                .find_session("session-1")
+                .unwrap()
+                .revoked_at()
+                .is_some()
+        );
+        assert!(matches!(
+            after_reuse,
+            Err(RefreshSessionError::InvalidRefreshToken())
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_unknown_token_should_return_error() {
+        // Arrange
+        let mock_repo = setup(Duration::days(1));
+        let use_case = RefreshSessionUseCase::new(mock_repo.clone(), jwt_settings());
+
+        // Act
+        let result = use_case
+            .execute(RefreshSessionRequest::new("unknown-token".to_string()))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(RefreshSessionError::InvalidRefreshToken())
+        ));
+        assert!(
+            mock_repo
+                .find_session("session-1")
+                .unwrap()
+                .revoked_at()
+                .is_none()
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_expired_session_should_return_error() {
+        // Arrange
+        let mock_repo = setup(Duration::seconds(-1));
+        let use_case = RefreshSessionUseCase::new(mock_repo.clone(), jwt_settings());
+
+        // Act
+        let result = use_case
+            .execute(RefreshSessionRequest::new("refresh-token".to_string()))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(RefreshSessionError::InvalidRefreshToken())
+        ));
+    }
+}
@@ -1,18 +1,26 @@
+use std::fmt;
 use std::sync::Arc;
 
-use argon2::{
-    Argon2,
-    password_hash::{PasswordHasher, SaltString, rand_core},
+use crate::app::{
+    entities::user::User,
+    errors::app_error::{AppError, InvalidParam},
+    repositories::{repository_error::RepositoryError, user_repository::UserRepository},
+    storage::object_storage::{ObjectStorage, StorageError},
+    use_cases::{
+        avatar_image::{IDENTICON_MIME_TYPE, generate_identicon, identicon_file_key},
+        get_signed_url_use_case::UPLOAD_URL_TTL,
+        password_hashing::hash_password,
+        update_avatar_use_case::{new_avatar_file_key, validate_avatar_mime_type},
+        user_validation::{normalize_email, validate_email, validate_password, validate_username},
+    },
 };
 
-use crate::app::{entities::user::User, repositories::user_repository::UserRepository};
-
 pub struct RegisterUserRequest {
     pub email: String,
     pub username: String,
     pub password: String,
-    pub file_key: String,
-    pub mime_type: String,
+    /// Type of the picture the client wants to upload, if any.
+    pub avatar_mime_type: Option<String>,
 }
 
 impl RegisterUserRequest {
@@ -20,42 +28,191 @@ impl RegisterUserRequest {
         email: String,
         username: String,
         password: String,
-        file_key: String,
-        mime_type: String,
+        avatar_mime_type: Option<String>,
     ) -> Self {
         Self {
             email,
             username,
             password,
-            file_key,
-            mime_type,
+            avatar_mime_type,
+        }
+    }
+}
+
+/// Where the client uploads its picture. It becomes the avatar once the
+/// upload is confirmed with `PUT /users/avatar`; until then the user has the
+/// generated identicon. A fresh URL can be asked for at any time from
+/// `POST /users/avatar/upload-url`.
+pub struct AvatarUpload {
+    pub url: String,
+    pub file_key: String,
+}
+
+pub struct RegisterUserResponse {
+    pub user_id: String,
+    pub avatar_upload: Option<AvatarUpload>,
+}
+
+#[derive(Debug)]
+pub enum RegisterUserError {
+    Validation(Vec<InvalidParam>),
+    EmailAlreadyRegistered(),
+    PasswordHashing(String),
+    Storage(StorageError),
+    Repository(RepositoryError),
+}
+
+impl From<StorageError> for RegisterUserError {
+    fn from(error: StorageError) -> Self {
+        RegisterUserError::Storage(error)
+    }
+}
+
+impl From<RepositoryError> for RegisterUserError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::Conflict(_) => RegisterUserError::EmailAlreadyRegistered(),
+            error => RegisterUserError::Repository(error),
+        }
+    }
+}
+
+impl From<RegisterUserError> for AppError {
+    fn from(error: RegisterUserError) -> Self {
+        match error {
+            RegisterUserError::Validation(invalid_params) => {
+                AppError::unprocessable("validation_failed", "One or more fields are invalid")
+                    .with_invalid_params(invalid_params)
+            }
+            RegisterUserError::EmailAlreadyRegistered() => {
+                AppError::conflict("email_already_registered", error.to_string())
+            }
+            RegisterUserError::Repository(e) => e.into(),
+            RegisterUserError::PasswordHashing(_) => AppError::internal(error.to_string()),
+            RegisterUserError::Storage(_) => {
+                AppError::bad_gateway("object_storage_unavailable", error.to_string())
+            }
+        }
+    }
+}
+
+impl fmt::Display for RegisterUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterUserError::Validation(invalid_params) => {
+                let fields: Vec<&str> = invalid_params.iter().map(|param| param.name).collect();
+                write!(f, "Invalid fields: {}", fields.join(", "))
+            }
+            RegisterUserError::EmailAlreadyRegistered() => {
+                write!(f, "A user with this email already exists")
+            }
+            RegisterUserError::PasswordHashing(e) => write!(f, "Failed to hash password: {}", e),
+            RegisterUserError::Storage(e) => write!(f, "{}", e),
+            RegisterUserError::Repository(e) => write!(f, "{}", e),
         }
     }
 }
 
 pub struct RegisterUserUseCase {
     user_repository: Arc<dyn UserRepository + Send + Sync>,
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
 }
 
 impl RegisterUserUseCase {
-    pub fn new(user_repository: Arc<dyn UserRepository + Send + Sync>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            object_storage,
+        }
     }
 
-    pub async fn execute(&self, request: RegisterUserRequest) {
-        let mut user = User::new(request.email, request.username, request.password);
-        let salt = SaltString::generate(rand_core::OsRng);
-        let argon2 = Argon2::default();
+    /// Registers the user with a generated identicon and, when asked, signs
+    /// an upload URL for their picture. Everything that can fail happens
+    /// before the user is committed, so a failed registration leaves no
+    /// account behind and can simply be retried.
+    pub async fn execute(
+        &self,
+        request: RegisterUserRequest,
+    ) -> Result<RegisterUserResponse, RegisterUserError> {
+        let email = normalize_email(&request.email);
+        let invalid_params: Vec<InvalidParam> = [
+            validate_email(&email),
+            validate_username(&request.username),
+            validate_password("password", &request.password),
+            request
+                .avatar_mime_type
+                .as_deref()
+                .and_then(validate_avatar_mime_type),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !invalid_params.is_empty() {
+            return Err(RegisterUserError::Validation(invalid_params));
+        }
+
+        let mut user = User::new(email, request.username, request.password);
 
-        let password_hash = argon2
-            .hash_password(user.password().as_bytes(), &salt)
-            .expect("Failed to hash password")
-            .to_string();
+        let password_hash = hash_password(user.password())
+            .map_err(|e| RegisterUserError::PasswordHashing(e.to_string()))?;
 
         user.set_password(password_hash);
+        // Assigned here so the generated avatar can be keyed by it.
+        let user_id = uuid::Uuid::new_v4().to_string();
+        user.set_id(user_id.clone());
 
-        self.user_repository
-            .register(user, request.file_key, request.mime_type)
+        let identicon_key = identicon_file_key(&user_id);
+        self.object_storage
+            .put(
+                identicon_key.clone(),
+                IDENTICON_MIME_TYPE.to_string(),
+                generate_identicon(&user_id),
+            )
+            .await?;
+
+        let result = self
+            .sign_upload_and_register(user, &identicon_key, request.avatar_mime_type)
             .await;
+        if result.is_err()
+            && let Err(e) = self.object_storage.delete(identicon_key.clone()).await
+        {
+            tracing::warn!(file_key = %identicon_key, error = %e, "failed to delete avatar");
+        }
+        result
+    }
+
+    async fn sign_upload_and_register(
+        &self,
+        user: User,
+        identicon_key: &str,
+        avatar_mime_type: Option<String>,
+    ) -> Result<RegisterUserResponse, RegisterUserError> {
+        let user_id = user.id().cloned().expect("the user id is assigned before");
+        let avatar_upload = match avatar_mime_type {
+            Some(mime_type) => {
+                let file_key = new_avatar_file_key(&user_id);
+                let url = self
+                    .object_storage
+                    .presign_put(file_key.clone(), mime_type, UPLOAD_URL_TTL)
+                    .await?;
+                Some(AvatarUpload { url, file_key })
+            }
+            None => None,
+        };
+
+        self.user_repository
+            .register(
+                user,
+                identicon_key.to_string(),
+                IDENTICON_MIME_TYPE.to_string(),
+            )
+            .await?;
+        Ok(RegisterUserResponse {
+            user_id,
+            avatar_upload,
+        })
     }
 }
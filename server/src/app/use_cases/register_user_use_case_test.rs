@@ -1,109 +1,186 @@
 #[cfg(test)]
 mod tests {
-    use crate::app::entities::user::User;
-    use crate::app::repositories::user_repository::{UserProfile, UserRepository};
-    use crate::app::use_cases::register_user_use_case::{RegisterUserRequest, RegisterUserUseCase};
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::storage::mock_object_storage::MockObjectStorage;
+    use crate::app::use_cases::avatar_image::{
+        IDENTICON_SIZE, generate_identicon, identicon_file_key,
+    };
+    use crate::app::use_cases::register_user_use_case::{
+        RegisterUserError, RegisterUserRequest, RegisterUserUseCase,
+    };
     use argon2::Argon2;
     use argon2::password_hash::PasswordVerifier;
-    use std::future::Future;
-    use std::pin::Pin;
-    use std::sync::{Arc, Mutex};
-
-    struct MockUserRepository {
-        // email, username, password_hash, file_key, mime_type
-        register_called_with: Mutex<Option<(String, String, String, String, String)>>,
-    }
-
-    impl MockUserRepository {
-        fn new() -> Self {
-            Self {
-                register_called_with: Mutex::new(None),
-            }
-        }
-    }
-
-    impl UserRepository for MockUserRepository {
-        fn register<'a>(
-            &'a self,
-            user: User,
-            file_key: String,
-            mime_type: String,
-        ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
-            let mut call_data = self.register_called_with.lock().unwrap();
-            *call_data = Some((
-                user.email().to_string(),
-                user.username().to_string(),
-                user.password().to_string(),
-                file_key,
-                mime_type,
-            ));
-            Box::pin(async {})
-        }
-
-        fn get_user_by_email<'a>(
-            &'a self,
-            email: String,
-        ) -> Pin<Box<dyn Future<Output = Option<User>> + Send + 'a>> {
-            todo!()
-        }
-
-        fn get_user_profile<'a>(
-            &'a self,
-            user_id: String,
-        ) -> Pin<Box<dyn Future<Output = Option<UserProfile>> + Send>> {
-            todo!()
-        }
-    }
+    use std::sync::Arc;
 
     #[actix_web::test]
     async fn test_execute_should_hash_password_and_register_user() {
         // Arrange
         let mock_repo = Arc::new(MockUserRepository::new());
-        let use_case = RegisterUserUseCase::new(mock_repo.clone());
+        let use_case =
+            RegisterUserUseCase::new(mock_repo.clone(), Arc::new(MockObjectStorage::new()));
 
         let email = "test@example.com".to_string();
         let username = "testuser".to_string();
         let password = "password123".to_string();
-        let file_key = "test_file_key".to_string();
-        let mime_type = "image/png".to_string();
 
         let request = RegisterUserRequest::new(
             email.clone(),
             username.clone(),
             password.clone(),
-            file_key.clone(),
-            mime_type.clone(),
+            Some("image/png".to_string()),
         );
 
         // Act
-        use_case.execute(request).await;
+        let result = use_case.execute(request).await;
 
         // Assert
-        let call_data_lock = mock_repo.register_called_with.lock().unwrap();
-        let call_data = call_data_lock.as_ref();
-
-        assert!(call_data.is_some(), "register was not called");
-
-        if let Some((
-            saved_email,
-            saved_username,
-            saved_password_hash,
-            saved_file_key,
-            saved_mime_type,
-        )) = call_data
-        {
-            assert_eq!(saved_email, &email);
-            assert_eq!(saved_username, &username);
-            assert_ne!(saved_password_hash, &password);
-
-            let is_valid = argon2::PasswordHash::new(saved_password_hash)
-                .and_then(|hash| Argon2::default().verify_password(password.as_bytes(), &hash))
-                .is_ok();
-
-            assert!(is_valid, "password was not hashed correctly");
-
-            assert_eq!(saved_file_key, &file_key);
-            assert_eq!(saved_mime_type, &mime_type);
-        }
+        let response = result.unwrap();
+        let upload = response.avatar_upload.unwrap();
+        assert!(
+            upload
+                .file_key
+                .starts_with(&format!("{}-", response.user_id))
+        );
+        assert_eq!(
+            upload.url,
+            format!(
+                "mock://put/{}?content_type=image/png&expires_in=600",
+                upload.file_key
+            )
+        );
+        let user = mock_repo
+            .find_user_by_email(&email)
+            .expect("register was not called");
+        assert_eq!(user.id(), Some(&response.user_id));
+        assert_eq!(user.username(), username);
+        assert_ne!(user.password(), password);
+
+        let is_valid = argon2::PasswordHash::new(user.password())
+            .and_then(|hash| Argon2::default().verify_password(password.as_bytes(), &hash))
+            .is_ok();
+
+        assert!(is_valid, "password was not hashed correctly");
+
+        // The upload only becomes the avatar once it is confirmed.
+        let avatars = mock_repo.avatars.lock().unwrap();
+        assert_eq!(avatars[0].file_key(), identicon_file_key(&response.user_id));
+        assert_eq!(avatars[0].mime_type(), "image/png");
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_invalid_fields_should_report_each_field() {
+        // Arrange
+        let mock_repo = Arc::new(MockUserRepository::new());
+        let use_case =
+            RegisterUserUseCase::new(mock_repo.clone(), Arc::new(MockObjectStorage::new()));
+        let request = RegisterUserRequest::new(
+            "not-an-email".to_string(),
+            "u".repeat(61),
+            "password".to_string(),
+            Some("text/html".to_string()),
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        let Err(RegisterUserError::Validation(invalid_params)) = result else {
+            panic!("expected a validation error");
+        };
+        let fields: Vec<&str> = invalid_params.iter().map(|param| param.name).collect();
+        assert_eq!(fields, vec!["email", "username", "password", "mime_type"]);
+        assert!(mock_repo.users.lock().unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_email_differing_only_in_case_should_conflict() {
+        // Arrange
+        let user_repo = Arc::new(MockUserRepository::new());
+        let storage = Arc::new(MockObjectStorage::new());
+        let use_case = RegisterUserUseCase::new(user_repo.clone(), storage.clone());
+        let request = |email: &str| {
+            RegisterUserRequest::new(
+                email.to_string(),
+                "testuser".to_string(),
+                "password123".to_string(),
+                None,
+            )
+        };
+        use_case.execute(request("test@example.com")).await.unwrap();
+
+        // Act
+        let result = use_case.execute(request(" Test@Example.COM ")).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(RegisterUserError::EmailAlreadyRegistered())
+        ));
+        assert_eq!(user_repo.users.lock().unwrap().len(), 1);
+        // The identicon stored for the rejected registration is cleaned up.
+        assert_eq!(storage.objects.lock().unwrap().len(), 1);
+        assert_eq!(storage.deleted.lock().unwrap().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_execute_when_storage_fails_should_not_register_user() {
+        // Arrange
+        let mock_repo = Arc::new(MockUserRepository::new());
+        let storage = Arc::new(MockObjectStorage::new());
+        storage.fail_with("storage is down");
+        let use_case = RegisterUserUseCase::new(mock_repo.clone(), storage);
+        let request = RegisterUserRequest::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+            Some("image/png".to_string()),
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(matches!(result, Err(RegisterUserError::Storage(_))));
+        assert!(mock_repo.users.lock().unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_without_upload_should_store_generated_identicon() {
+        // Arrange
+        let user_repo = Arc::new(MockUserRepository::new());
+        let storage = Arc::new(MockObjectStorage::new());
+        let use_case = RegisterUserUseCase::new(user_repo.clone(), storage.clone());
+        let request = RegisterUserRequest::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+            None,
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.unwrap().avatar_upload.is_none());
+        let user_id = user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+        let avatars = user_repo.avatars.lock().unwrap();
+        assert_eq!(avatars[0].file_key(), identicon_file_key(&user_id));
+        assert_eq!(avatars[0].mime_type(), "image/png");
+
+        let (content_type, identicon) =
+            storage.objects.lock().unwrap()[avatars[0].file_key()].clone();
+        assert_eq!(content_type, "image/png");
+        assert_eq!(identicon, generate_identicon(&user_id));
+        let image = image::load_from_memory(&identicon).unwrap();
+        assert_eq!(
+            (image.width(), image.height()),
+            (IDENTICON_SIZE, IDENTICON_SIZE)
+        );
+        assert_ne!(identicon, generate_identicon("another-user"));
     }
 }
@@ -0,0 +1,122 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::app::{
+    entities::password_reset_token::PasswordResetToken,
+    errors::app_error::AppError,
+    notifiers::notifier::{Notification, Notifier, NotifierError},
+    repositories::{
+        password_reset_repository::PasswordResetRepository, repository_error::RepositoryError,
+        user_repository::UserRepository,
+    },
+    use_cases::opaque_tokens::{generate_opaque_token, hash_opaque_token},
+};
+
+pub const DEFAULT_PASSWORD_RESET_TOKEN_TTL_SECS: i64 = 60 * 60;
+
+pub struct RequestPasswordResetRequest {
+    email: String,
+}
+
+impl RequestPasswordResetRequest {
+    pub fn new(email: String) -> Self {
+        Self { email }
+    }
+}
+
+#[derive(Debug)]
+pub enum RequestPasswordResetError {
+    Notifier(NotifierError),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for RequestPasswordResetError {
+    fn from(error: RepositoryError) -> Self {
+        RequestPasswordResetError::Repository(error)
+    }
+}
+
+impl From<NotifierError> for RequestPasswordResetError {
+    fn from(error: NotifierError) -> Self {
+        RequestPasswordResetError::Notifier(error)
+    }
+}
+
+impl From<RequestPasswordResetError> for AppError {
+    fn from(error: RequestPasswordResetError) -> Self {
+        match error {
+            RequestPasswordResetError::Notifier(_) => AppError::internal(error.to_string()),
+            RequestPasswordResetError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for RequestPasswordResetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestPasswordResetError::Notifier(e) => write!(f, "{}", e),
+            RequestPasswordResetError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct RequestPasswordResetUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    password_reset_repository: Arc<dyn PasswordResetRepository + Send + Sync>,
+    notifier: Arc<dyn Notifier + Send + Sync>,
+    token_ttl: Duration,
+}
+
+impl RequestPasswordResetUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        password_reset_repository: Arc<dyn PasswordResetRepository + Send + Sync>,
+        notifier: Arc<dyn Notifier + Send + Sync>,
+        token_ttl: Duration,
+    ) -> Self {
+        Self {
+            user_repository,
+            password_reset_repository,
+            notifier,
+            token_ttl,
+        }
+    }
+
+    /// Succeeds whether or not the email belongs to an account, so the
+    /// endpoint cannot be used to find out which emails are registered. It
+    /// does more work for known emails, so callers should not wait on it.
+    pub async fn execute(
+        &self,
+        request: RequestPasswordResetRequest,
+    ) -> Result<(), RequestPasswordResetError> {
+        let user = self
+            .user_repository
+            .get_user_by_email(request.email)
+            .await?;
+
+        let Some((user_id, email)) = user.and_then(|user| {
+            user.id()
+                .cloned()
+                .map(|user_id| (user_id, user.email().to_string()))
+        }) else {
+            return Ok(());
+        };
+
+        let token = generate_opaque_token();
+        self.password_reset_repository
+            .create(PasswordResetToken::new(
+                uuid::Uuid::new_v4().to_string(),
+                user_id,
+                hash_opaque_token(&token),
+                Utc::now() + self.token_ttl,
+            ))
+            .await?;
+
+        self.notifier
+            .send(Notification::PasswordReset { email, token })
+            .await?;
+        Ok(())
+    }
+}
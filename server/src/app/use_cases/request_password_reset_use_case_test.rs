@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Duration;
+
+    use crate::app::entities::user::User;
+    use crate::app::notifiers::mock_notifier::MockNotifier;
+    use crate::app::notifiers::notifier::Notification;
+    use crate::app::repositories::mock_password_reset_repository::MockPasswordResetRepository;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::use_cases::opaque_tokens::hash_opaque_token;
+    use crate::app::use_cases::request_password_reset_use_case::{
+        RequestPasswordResetRequest, RequestPasswordResetUseCase,
+    };
+
+    fn setup() -> (
+        Arc<MockPasswordResetRepository>,
+        Arc<MockNotifier>,
+        RequestPasswordResetUseCase,
+    ) {
+        let user_repo = Arc::new(MockUserRepository::new());
+        user_repo.add_user(User::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+        ));
+        let reset_repo = Arc::new(MockPasswordResetRepository::new());
+        let notifier = Arc::new(MockNotifier::new());
+        let use_case = RequestPasswordResetUseCase::new(
+            user_repo,
+            reset_repo.clone(),
+            notifier.clone(),
+            Duration::hours(1),
+        );
+        (reset_repo, notifier, use_case)
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_store_hashed_token_and_notify_user() {
+        // Arrange
+        let (reset_repo, notifier, use_case) = setup();
+        let request = RequestPasswordResetRequest::new("test@example.com".to_string());
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let sent = notifier.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        let Notification::PasswordReset { email, token } = &sent[0];
+        assert_eq!(email, "test@example.com");
+        let tokens = reset_repo.tokens.lock().unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token_hash(), hash_opaque_token(token));
+        assert_ne!(tokens[0].token_hash(), token);
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_unknown_email_should_succeed_silently() {
+        // Arrange
+        let (reset_repo, notifier, use_case) = setup();
+        let request = RequestPasswordResetRequest::new("nobody@example.com".to_string());
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(notifier.sent.lock().unwrap().is_empty());
+        assert!(reset_repo.tokens.lock().unwrap().is_empty());
+    }
+}
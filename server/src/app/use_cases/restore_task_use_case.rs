@@ -0,0 +1,82 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
+
+pub struct RestoreTaskRequest {
+    user_id: String,
+    task_id: String,
+}
+
+impl RestoreTaskRequest {
+    pub fn new(user_id: String, task_id: String) -> Self {
+        Self { user_id, task_id }
+    }
+}
+
+#[derive(Debug)]
+pub enum RestoreTaskError {
+    TaskNotFound(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for RestoreTaskError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => RestoreTaskError::TaskNotFound(),
+            error => RestoreTaskError::Repository(error),
+        }
+    }
+}
+
+impl From<RestoreTaskError> for AppError {
+    fn from(error: RestoreTaskError) -> Self {
+        match error {
+            RestoreTaskError::TaskNotFound() => {
+                AppError::not_found("task_not_found", error.to_string())
+            }
+            RestoreTaskError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for RestoreTaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreTaskError::TaskNotFound() => {
+                write!(f, "Task not found in trash")
+            }
+            RestoreTaskError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct RestoreTaskUseCase {
+    task_repository: Arc<dyn TaskRepository + Send + Sync>,
+}
+
+impl RestoreTaskUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository + Send + Sync>) -> Self {
+        Self { task_repository }
+    }
+
+    pub async fn execute(&self, request: RestoreTaskRequest) -> Result<(), RestoreTaskError> {
+        let task = self
+            .task_repository
+            .find_by_id(request.task_id.clone())
+            .await?;
+
+        match task {
+            Some(task) if task.user_id() == request.user_id && task.is_deleted() => {}
+            _ => return Err(RestoreTaskError::TaskNotFound()),
+        }
+
+        self.task_repository
+            .restore(request.user_id, request.task_id)
+            .await?;
+        Ok(())
+    }
+}
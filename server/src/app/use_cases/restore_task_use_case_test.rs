@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::app::entities::task::Task;
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::use_cases::restore_task_use_case::{
+        RestoreTaskError, RestoreTaskRequest, RestoreTaskUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_execute_should_bring_task_back_from_trash() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let mut task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        task.set_deleted_at(Some(Utc::now()));
+        let task_id = mock_repo.add_task(task);
+        let use_case = RestoreTaskUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(RestoreTaskRequest::new(
+                "user-1".to_string(),
+                task_id.clone(),
+            ))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(!mock_repo.find_task(&task_id).unwrap().is_deleted());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_task_not_in_trash_should_return_not_found() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        let task_id = mock_repo.add_task(task);
+        let use_case = RestoreTaskUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(RestoreTaskRequest::new("user-1".to_string(), task_id))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(RestoreTaskError::TaskNotFound())));
+    }
+}
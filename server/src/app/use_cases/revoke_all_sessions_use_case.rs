@@ -0,0 +1,64 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, session_repository::SessionRepository},
+};
+
+pub struct RevokeAllSessionsRequest {
+    user_id: String,
+}
+
+impl RevokeAllSessionsRequest {
+    pub fn new(user_id: String) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Debug)]
+pub enum RevokeAllSessionsError {
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for RevokeAllSessionsError {
+    fn from(error: RepositoryError) -> Self {
+        RevokeAllSessionsError::Repository(error)
+    }
+}
+
+impl From<RevokeAllSessionsError> for AppError {
+    fn from(error: RevokeAllSessionsError) -> Self {
+        match error {
+            RevokeAllSessionsError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for RevokeAllSessionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevokeAllSessionsError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct RevokeAllSessionsUseCase {
+    session_repository: Arc<dyn SessionRepository + Send + Sync>,
+}
+
+impl RevokeAllSessionsUseCase {
+    pub fn new(session_repository: Arc<dyn SessionRepository + Send + Sync>) -> Self {
+        Self { session_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        request: RevokeAllSessionsRequest,
+    ) -> Result<(), RevokeAllSessionsError> {
+        self.session_repository
+            .revoke_all_for_user(request.user_id)
+            .await?;
+        Ok(())
+    }
+}
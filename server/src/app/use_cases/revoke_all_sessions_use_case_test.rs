@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+
+    use crate::app::entities::session::Session;
+    use crate::app::repositories::mock_session_repository::MockSessionRepository;
+    use crate::app::use_cases::revoke_all_sessions_use_case::{
+        RevokeAllSessionsRequest, RevokeAllSessionsUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_execute_should_revoke_only_sessions_of_the_user() {
+        // Arrange
+        let mock_repo = Arc::new(MockSessionRepository::new());
+        for (session_id, user_id) in [("s-1", "user-1"), ("s-2", "user-1"), ("s-3", "user-2")] {
+            mock_repo.add_session(Session::new(
+                session_id.to_string(),
+                user_id.to_string(),
+                format!("hash-{}", session_id),
+                Utc::now() + Duration::days(1),
+            ));
+        }
+        let use_case = RevokeAllSessionsUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(RevokeAllSessionsRequest::new("user-1".to_string()))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(
+            mock_repo
+                .find_session("s-1")
+                .unwrap()
+                .revoked_at()
+                .is_some()
+        );
+        assert!(
+            mock_repo
+                .find_session("s-2")
+                .unwrap()
+                .revoked_at()
+                .is_some()
+        );
+        assert!(
+            mock_repo
+                .find_session("s-3")
+                .unwrap()
+                .revoked_at()
+                .is_none()
+        );
+    }
+}
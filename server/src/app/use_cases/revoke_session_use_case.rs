@@ -0,0 +1,71 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, session_repository::SessionRepository},
+    use_cases::session_tokens::hash_refresh_token,
+};
+
+pub struct RevokeSessionRequest {
+    refresh_token: String,
+}
+
+impl RevokeSessionRequest {
+    pub fn new(refresh_token: String) -> Self {
+        Self { refresh_token }
+    }
+}
+
+#[derive(Debug)]
+pub enum RevokeSessionError {
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for RevokeSessionError {
+    fn from(error: RepositoryError) -> Self {
+        RevokeSessionError::Repository(error)
+    }
+}
+
+impl From<RevokeSessionError> for AppError {
+    fn from(error: RevokeSessionError) -> Self {
+        match error {
+            RevokeSessionError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for RevokeSessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevokeSessionError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct RevokeSessionUseCase {
+    session_repository: Arc<dyn SessionRepository + Send + Sync>,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(session_repository: Arc<dyn SessionRepository + Send + Sync>) -> Self {
+        Self { session_repository }
+    }
+
+    /// Logging out with an unknown or already revoked token is not an error,
+    /// the caller ends up logged out either way.
+    pub async fn execute(&self, request: RevokeSessionRequest) -> Result<(), RevokeSessionError> {
+        let session = self
+            .session_repository
+            .find_by_refresh_token_hash(hash_refresh_token(&request.refresh_token))
+            .await?;
+
+        if let Some(session) = session {
+            self.session_repository
+                .revoke(session.id().to_string())
+                .await?;
+        }
+        Ok(())
+    }
+}
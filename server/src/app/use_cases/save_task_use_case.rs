@@ -1,8 +1,13 @@
+use std::fmt;
 use std::sync::Arc;
 
-use chrono::{Date, DateTime, Local, Utc};
+use chrono::{DateTime, Utc};
 
-use crate::app::{entities::task::Task, repositories::task_repository::TaskRepository};
+use crate::app::{
+    entities::task::Task,
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
 
 pub struct SaveTaskRequest {
     pub user_id: String,
@@ -20,6 +25,41 @@ impl SaveTaskRequest {
     }
 }
 
+#[derive(Debug)]
+pub enum SaveTaskError {
+    UserNotFound(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for SaveTaskError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => SaveTaskError::UserNotFound(),
+            error => SaveTaskError::Repository(error),
+        }
+    }
+}
+
+impl From<SaveTaskError> for AppError {
+    fn from(error: SaveTaskError) -> Self {
+        match error {
+            SaveTaskError::UserNotFound() => {
+                AppError::not_found("user_not_found", error.to_string())
+            }
+            SaveTaskError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for SaveTaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveTaskError::UserNotFound() => write!(f, "User not found in database"),
+            SaveTaskError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
 pub struct SaveTaskUseCase {
     task_repository: Arc<dyn TaskRepository + Send + Sync>,
 }
@@ -29,9 +69,10 @@ impl SaveTaskUseCase {
         Self { task_repository }
     }
 
-    pub async fn execute(&self, request: SaveTaskRequest) {
+    pub async fn execute(&self, request: SaveTaskRequest) -> Result<(), SaveTaskError> {
         let task = Task::new(request.user_id, request.content, request.task_date);
 
-        self.task_repository.save(task).await;
+        self.task_repository.save(task).await?;
+        Ok(())
     }
 }
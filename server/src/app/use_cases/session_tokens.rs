@@ -0,0 +1,56 @@
+use chrono::Utc;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::use_cases::opaque_tokens::{generate_opaque_token, hash_opaque_token},
+    settings::JwtSettings,
+};
+
+pub const DEFAULT_ACCESS_TOKEN_TTL_SECS: i64 = 15 * 60;
+pub const DEFAULT_REFRESH_TOKEN_TTL_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// Claims carried by every access token. `sid` ties the token to a row in
+/// the sessions table so it stops working as soon as the session is revoked.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AccessTokenClaims {
+    pub sub: String,
+    pub sid: String,
+    pub exp: usize,
+}
+
+#[derive(Debug)]
+pub struct SessionTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
+}
+
+pub fn issue_access_token(
+    settings: &JwtSettings,
+    user_id: &str,
+    session_id: &str,
+) -> Result<(String, i64), jsonwebtoken::errors::Error> {
+    let ttl = settings.access_token_ttl();
+
+    let claims = AccessTokenClaims {
+        sub: user_id.to_string(),
+        sid: session_id.to_string(),
+        exp: (Utc::now() + ttl).timestamp() as usize,
+    };
+    let token = encode(
+        &Header::new(Algorithm::HS512),
+        &claims,
+        &EncodingKey::from_secret(settings.secret.as_ref()),
+    )?;
+
+    Ok((token, ttl.num_seconds()))
+}
+
+pub fn generate_refresh_token() -> String {
+    generate_opaque_token()
+}
+
+pub fn hash_refresh_token(refresh_token: &str) -> String {
+    hash_opaque_token(refresh_token)
+}
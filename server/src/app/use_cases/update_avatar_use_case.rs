@@ -0,0 +1,270 @@
+use std::fmt;
+use std::sync::Arc;
+
+use actix_web::web;
+
+use crate::app::{
+    errors::app_error::{AppError, InvalidParam},
+    repositories::{repository_error::RepositoryError, user_repository::UserRepository},
+    storage::object_storage::{ObjectBytes, ObjectStorage, StorageError},
+    use_cases::avatar_image::{
+        AVATAR_MAX_BYTES, AVATAR_MIME_TYPES, AvatarImageError, IDENTICON_MIME_TYPE,
+        generate_identicon, identicon_file_key, is_thumbnail_file_key, process_avatar_image,
+        thumbnail_file_key,
+    },
+};
+
+/// Builds a fresh object key for an avatar upload. Keys start with the
+/// owner's id so a confirmation can only point at the caller's own uploads.
+pub fn new_avatar_file_key(user_id: &str) -> String {
+    format!("{}-{}", user_id, uuid::Uuid::new_v4())
+}
+
+fn is_avatar_file_key_of(user_id: &str, file_key: &str) -> bool {
+    !is_thumbnail_file_key(file_key)
+        && file_key
+            .strip_prefix(user_id)
+            .is_some_and(|rest| rest.starts_with('-') && rest.len() > 1)
+}
+
+pub fn validate_avatar_mime_type(mime_type: &str) -> Option<InvalidParam> {
+    if AVATAR_MIME_TYPES.contains(&mime_type) {
+        return None;
+    }
+    Some(InvalidParam::new(
+        "mime_type",
+        format!("must be one of {}", AVATAR_MIME_TYPES.join(", ")),
+    ))
+}
+
+pub enum AvatarChange {
+    /// Points the avatar at an object the client uploaded through a URL
+    /// from the upload endpoint. The upload is checked and re-encoded
+    /// without metadata under a new key with a thumbnail next to it, and the
+    /// sniffed content type wins over `mime_type`.
+    Uploaded { file_key: String, mime_type: String },
+    /// Goes back to the identicon generated from the user id.
+    Default,
+}
+
+pub struct UpdateAvatarRequest {
+    user_id: String,
+    change: AvatarChange,
+}
+
+impl UpdateAvatarRequest {
+    pub fn new(user_id: String, change: AvatarChange) -> Self {
+        Self { user_id, change }
+    }
+}
+
+#[derive(Debug)]
+pub enum UpdateAvatarError {
+    AvatarNotFound(),
+    Validation(Vec<InvalidParam>),
+    ImageProcessing(String),
+    Storage(StorageError),
+    Repository(RepositoryError),
+}
+
+impl From<StorageError> for UpdateAvatarError {
+    fn from(error: StorageError) -> Self {
+        UpdateAvatarError::Storage(error)
+    }
+}
+
+impl From<RepositoryError> for UpdateAvatarError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => UpdateAvatarError::AvatarNotFound(),
+            error => UpdateAvatarError::Repository(error),
+        }
+    }
+}
+
+impl From<UpdateAvatarError> for AppError {
+    fn from(error: UpdateAvatarError) -> Self {
+        match error {
+            UpdateAvatarError::AvatarNotFound() => {
+                AppError::not_found("avatar_not_found", error.to_string())
+            }
+            UpdateAvatarError::Validation(invalid_params) => {
+                AppError::unprocessable("validation_failed", "One or more fields are invalid")
+                    .with_invalid_params(invalid_params)
+            }
+            UpdateAvatarError::ImageProcessing(_) => AppError::internal(error.to_string()),
+            UpdateAvatarError::Storage(_) => {
+                AppError::bad_gateway("object_storage_unavailable", error.to_string())
+            }
+            UpdateAvatarError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateAvatarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateAvatarError::AvatarNotFound() => write!(f, "User has no avatar"),
+            UpdateAvatarError::Validation(invalid_params) => {
+                let fields: Vec<&str> = invalid_params.iter().map(|param| param.name).collect();
+                write!(f, "Invalid fields: {}", fields.join(", "))
+            }
+            UpdateAvatarError::ImageProcessing(e) => {
+                write!(f, "Failed to process the avatar image: {}", e)
+            }
+            UpdateAvatarError::Storage(e) => write!(f, "{}", e),
+            UpdateAvatarError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct UpdateAvatarUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+}
+
+impl UpdateAvatarUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository + Send + Sync>,
+        object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            user_repository,
+            object_storage,
+        }
+    }
+
+    /// Validates the upload under `file_key` and stores the cleaned image and
+    /// its thumbnail under a fresh key, returned with the sniffed type and
+    /// the thumbnail key. The upload itself is deleted either way: the
+    /// client can still `PUT` to its key until the URL expires, so the
+    /// avatar must never point there.
+    async fn process_upload(
+        &self,
+        user_id: &str,
+        file_key: &str,
+    ) -> Result<(String, &'static str, String), UpdateAvatarError> {
+        let processed = match self
+            .object_storage
+            .get(file_key.to_string(), AVATAR_MAX_BYTES)
+            .await?
+        {
+            ObjectBytes::Missing => {
+                return Err(UpdateAvatarError::Validation(vec![InvalidParam::new(
+                    "file_key",
+                    "has not been uploaded",
+                )]));
+            }
+            ObjectBytes::TooLarge => Err(AvatarImageError::TooLarge),
+            // Decoding and resizing take long enough to stall the worker.
+            ObjectBytes::Found(bytes) => web::block(move || process_avatar_image(&bytes))
+                .await
+                .map_err(|e| UpdateAvatarError::ImageProcessing(e.to_string()))?,
+        };
+
+        let processed = match processed {
+            Ok(processed) => processed,
+            Err(e) => {
+                if let Err(e) = self.object_storage.delete(file_key.to_string()).await {
+                    tracing::warn!(file_key = %file_key, error = %e, "failed to delete rejected avatar");
+                }
+                let reason = match e {
+                    AvatarImageError::TooLarge => {
+                        format!("must be at most {} bytes", AVATAR_MAX_BYTES)
+                    }
+                    AvatarImageError::Unsupported => {
+                        "must be a JPEG, PNG, GIF or WebP image".to_string()
+                    }
+                };
+                return Err(UpdateAvatarError::Validation(vec![InvalidParam::new(
+                    "file_key", reason,
+                )]));
+            }
+        };
+
+        let avatar_key = new_avatar_file_key(user_id);
+        let thumbnail_key = thumbnail_file_key(&avatar_key);
+        self.object_storage
+            .put(
+                avatar_key.clone(),
+                processed.mime_type.to_string(),
+                processed.image,
+            )
+            .await?;
+        self.object_storage
+            .put(
+                thumbnail_key.clone(),
+                processed.mime_type.to_string(),
+                processed.thumbnail,
+            )
+            .await?;
+        if let Err(e) = self.object_storage.delete(file_key.to_string()).await {
+            tracing::warn!(file_key = %file_key, error = %e, "failed to delete processed upload");
+        }
+        Ok((avatar_key, processed.mime_type, thumbnail_key))
+    }
+
+    pub async fn execute(&self, request: UpdateAvatarRequest) -> Result<(), UpdateAvatarError> {
+        let (file_key, mime_type, thumbnail_key) = match request.change {
+            AvatarChange::Uploaded {
+                file_key,
+                mime_type,
+            } => {
+                let mut invalid_params: Vec<InvalidParam> =
+                    validate_avatar_mime_type(&mime_type).into_iter().collect();
+                if !is_avatar_file_key_of(&request.user_id, &file_key) {
+                    invalid_params
+                        .push(InvalidParam::new("file_key", "was not issued to this user"));
+                }
+                if !invalid_params.is_empty() {
+                    return Err(UpdateAvatarError::Validation(invalid_params));
+                }
+                let (avatar_key, mime_type, thumbnail_key) =
+                    self.process_upload(&request.user_id, &file_key).await?;
+                (avatar_key, mime_type.to_string(), Some(thumbnail_key))
+            }
+            AvatarChange::Default => {
+                // Deterministic, so storing it again is harmless.
+                let identicon_key = identicon_file_key(&request.user_id);
+                self.object_storage
+                    .put(
+                        identicon_key.clone(),
+                        IDENTICON_MIME_TYPE.to_string(),
+                        generate_identicon(&request.user_id),
+                    )
+                    .await?;
+                (identicon_key, IDENTICON_MIME_TYPE.to_string(), None)
+            }
+        };
+
+        let previous = self
+            .user_repository
+            .list_avatars(request.user_id.clone())
+            .await?;
+
+        self.user_repository
+            .update_avatar(
+                request.user_id,
+                file_key.clone(),
+                mime_type,
+                thumbnail_key.clone(),
+            )
+            .await?;
+
+        // The old picture is unreachable from now on. Every key belongs to
+        // one user, so nothing else can refer to it, and a failed delete only
+        // leaves an orphan behind.
+        for avatar in previous {
+            let old_keys = std::iter::once(avatar.file_key()).chain(avatar.thumbnail_file_key());
+            for old_key in old_keys {
+                if old_key == file_key || Some(old_key) == thumbnail_key.as_deref() {
+                    continue;
+                }
+                if let Err(e) = self.object_storage.delete(old_key.to_string()).await {
+                    tracing::warn!(file_key = %old_key, error = %e, "failed to delete avatar");
+                }
+            }
+        }
+        Ok(())
+    }
+}
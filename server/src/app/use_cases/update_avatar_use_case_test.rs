@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use image::{DynamicImage, GenericImageView, ImageFormat};
+
+    use crate::app::entities::user::User;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::storage::mock_object_storage::MockObjectStorage;
+    use crate::app::use_cases::avatar_image::{
+        AVATAR_MAX_BYTES, THUMBNAIL_SIZE, generate_identicon, identicon_file_key,
+        thumbnail_file_key,
+    };
+    use crate::app::use_cases::update_avatar_use_case::{
+        AvatarChange, UpdateAvatarError, UpdateAvatarRequest, UpdateAvatarUseCase,
+        new_avatar_file_key, validate_avatar_mime_type,
+    };
+
+    fn setup() -> (
+        String,
+        Arc<MockUserRepository>,
+        Arc<MockObjectStorage>,
+        UpdateAvatarUseCase,
+    ) {
+        let user_repo = Arc::new(MockUserRepository::new());
+        user_repo.add_user(User::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+        ));
+        let user_id = user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+        let storage = Arc::new(MockObjectStorage::new());
+        let use_case = UpdateAvatarUseCase::new(user_repo.clone(), storage.clone());
+        (user_id, user_repo, storage, use_case)
+    }
+
+    fn avatar_of(user_repo: &MockUserRepository, user_id: &str) -> (String, String) {
+        let avatars = user_repo.avatars.lock().unwrap();
+        let avatar = avatars.iter().find(|a| a.user_id() == user_id).unwrap();
+        (
+            avatar.file_key().to_string(),
+            avatar.mime_type().to_string(),
+        )
+    }
+
+    fn encoded_image(format: ImageFormat) -> Vec<u8> {
+        let image = DynamicImage::new_rgb8(300, 200);
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, format).unwrap();
+        bytes.into_inner()
+    }
+
+    fn uploaded(user_id: &str, file_key: &str, mime_type: &str) -> UpdateAvatarRequest {
+        UpdateAvatarRequest::new(
+            user_id.to_string(),
+            AvatarChange::Uploaded {
+                file_key: file_key.to_string(),
+                mime_type: mime_type.to_string(),
+            },
+        )
+    }
+
+    fn stored(storage: &MockObjectStorage, key: &str) -> Option<(String, Vec<u8>)> {
+        storage.objects.lock().unwrap().get(key).cloned()
+    }
+
+    #[actix_web::test]
+    async fn test_execute_uploaded_should_switch_avatar_and_delete_old_object() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let file_key = new_avatar_file_key(&user_id);
+        // The client claims a JPEG but uploads a PNG.
+        storage.insert(&file_key, "image/jpeg", encoded_image(ImageFormat::Png));
+
+        // Act
+        let result = use_case
+            .execute(uploaded(&user_id, &file_key, "image/jpeg"))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        let (avatar_key, mime_type) = avatar_of(&user_repo, &user_id);
+        assert_eq!(mime_type, "image/png");
+        assert_ne!(avatar_key, file_key);
+        assert!(avatar_key.starts_with(&format!("{}-", user_id)));
+        let (content_type, thumbnail) = stored(&storage, &thumbnail_file_key(&avatar_key)).unwrap();
+        assert_eq!(content_type, "image/png");
+        assert_eq!(
+            image::load_from_memory(&thumbnail).unwrap().dimensions(),
+            (THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        );
+        // The raw upload and the previous avatar are both gone.
+        assert_eq!(
+            *storage.deleted.lock().unwrap(),
+            vec![file_key, "file_key".to_string()]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_uploaded_then_upload_again_should_keep_processed_avatar() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let file_key = new_avatar_file_key(&user_id);
+        storage.insert(&file_key, "image/png", encoded_image(ImageFormat::Png));
+        use_case
+            .execute(uploaded(&user_id, &file_key, "image/png"))
+            .await
+            .unwrap();
+        let (avatar_key, _) = avatar_of(&user_repo, &user_id);
+        let processed = stored(&storage, &avatar_key).unwrap();
+
+        // Act
+        // The presigned URL of the upload is still valid after confirming.
+        storage.insert(&file_key, "image/png", b"<svg></svg>".to_vec());
+
+        // Assert
+        assert_eq!(avatar_of(&user_repo, &user_id).0, avatar_key);
+        assert_eq!(stored(&storage, &avatar_key).unwrap(), processed);
+        assert!(image::load_from_memory(&processed.1).is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_execute_uploaded_should_strip_exif_metadata() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let file_key = new_avatar_file_key(&user_id);
+        let jpeg = encoded_image(ImageFormat::Jpeg);
+        let exif = b"Exif\0\0MM\0\x2a\0\0\0\x08\0\0";
+        let mut upload = jpeg[..2].to_vec();
+        upload.extend_from_slice(&[0xFF, 0xE1, 0, (exif.len() + 2) as u8]);
+        upload.extend_from_slice(exif);
+        upload.extend_from_slice(&jpeg[2..]);
+        storage.insert(&file_key, "image/jpeg", upload);
+
+        // Act
+        let result = use_case
+            .execute(uploaded(&user_id, &file_key, "image/jpeg"))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        let (avatar_key, mime_type) = avatar_of(&user_repo, &user_id);
+        assert_eq!(mime_type, "image/jpeg");
+        let (_, image) = stored(&storage, &avatar_key).unwrap();
+        assert!(!image.windows(4).any(|window| window == b"Exif"));
+        assert_eq!(
+            image::load_from_memory(&image).unwrap().dimensions(),
+            (300, 200)
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_uploaded_non_image_should_fail_and_delete_upload() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let file_key = new_avatar_file_key(&user_id);
+        storage.insert(&file_key, "image/png", b"<html></html>".to_vec());
+
+        // Act
+        let result = use_case
+            .execute(uploaded(&user_id, &file_key, "image/png"))
+            .await;
+
+        // Assert
+        let Err(UpdateAvatarError::Validation(invalid_params)) = result else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(invalid_params[0].name, "file_key");
+        assert_eq!(avatar_of(&user_repo, &user_id).0, "file_key");
+        assert!(stored(&storage, &file_key).is_none());
+        assert_eq!(*storage.deleted.lock().unwrap(), vec![file_key]);
+    }
+
+    #[actix_web::test]
+    async fn test_execute_uploaded_oversized_file_should_fail_and_delete_upload() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let file_key = new_avatar_file_key(&user_id);
+        storage.insert(&file_key, "image/png", vec![0; AVATAR_MAX_BYTES + 1]);
+
+        // Act
+        let result = use_case
+            .execute(uploaded(&user_id, &file_key, "image/png"))
+            .await;
+
+        // Assert
+        let Err(UpdateAvatarError::Validation(invalid_params)) = result else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(
+            invalid_params[0].reason,
+            format!("must be at most {} bytes", AVATAR_MAX_BYTES)
+        );
+        assert_eq!(avatar_of(&user_repo, &user_id).0, "file_key");
+        assert_eq!(*storage.deleted.lock().unwrap(), vec![file_key]);
+    }
+
+    #[actix_web::test]
+    async fn test_execute_uploaded_without_upload_should_fail_validation() {
+        // Arrange
+        let (user_id, _, _, use_case) = setup();
+        let file_key = new_avatar_file_key(&user_id);
+
+        // Act
+        let result = use_case
+            .execute(uploaded(&user_id, &file_key, "image/png"))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(UpdateAvatarError::Validation(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_foreign_file_key_should_fail_validation() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        let request = UpdateAvatarRequest::new(
+            user_id.clone(),
+            AvatarChange::Uploaded {
+                file_key: new_avatar_file_key("someone-else"),
+                mime_type: "text/html".to_string(),
+            },
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        let Err(UpdateAvatarError::Validation(invalid_params)) = result else {
+            panic!("expected a validation error");
+        };
+        let fields: Vec<&str> = invalid_params.iter().map(|param| param.name).collect();
+        assert_eq!(fields, vec!["mime_type", "file_key"]);
+        assert_eq!(avatar_of(&user_repo, &user_id).0, "file_key");
+        assert!(storage.deleted.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_avatar_mime_type_should_only_accept_decodable_formats() {
+        for mime_type in ["image/jpeg", "image/png", "image/gif", "image/webp"] {
+            assert!(validate_avatar_mime_type(mime_type).is_none());
+        }
+        for mime_type in ["image/svg+xml", "image/bmp", "text/html", "image/"] {
+            assert!(validate_avatar_mime_type(mime_type).is_some());
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_execute_default_should_switch_to_identicon_and_delete_legacy_key() {
+        // Arrange
+        let (user_id, user_repo, storage, use_case) = setup();
+        user_repo.avatars.lock().unwrap()[0].set_file_key("default.jpg-1".to_string());
+
+        // Act
+        for _ in 0..2 {
+            use_case
+                .execute(UpdateAvatarRequest::new(
+                    user_id.clone(),
+                    AvatarChange::Default,
+                ))
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        let identicon_key = identicon_file_key(&user_id);
+        assert_eq!(
+            avatar_of(&user_repo, &user_id),
+            (identicon_key.clone(), "image/png".to_string())
+        );
+        assert_eq!(
+            stored(&storage, &identicon_key),
+            Some(("image/png".to_string(), generate_identicon(&user_id)))
+        );
+        // The identicon itself survives switching to it a second time.
+        assert_eq!(
+            *storage.deleted.lock().unwrap(),
+            vec!["default.jpg-1".to_string()]
+        );
+    }
+}
@@ -1,8 +1,12 @@
 use std::fmt;
 use std::sync::Arc;
 
-use crate::app::entities::task_status::TaskStatus::*;
-use crate::app::{entities::task::Task, repositories::task_repository::TaskRepository};
+use crate::app::entities::task_status::TaskStatus::{self, *};
+use crate::app::{
+    entities::task::Task,
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -32,9 +36,37 @@ impl UpdateTaskStatusRequest {
     }
 }
 
+/// A status change the use case applied.
+#[derive(Debug, PartialEq)]
+pub struct TaskStatusTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+}
+
+#[derive(Debug)]
 pub enum UpdateTaskStatusError {
     TaskNotFound(),
-    RequestFailed(reqwest::Error),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for UpdateTaskStatusError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => UpdateTaskStatusError::TaskNotFound(),
+            error => UpdateTaskStatusError::Repository(error),
+        }
+    }
+}
+
+impl From<UpdateTaskStatusError> for AppError {
+    fn from(error: UpdateTaskStatusError) -> Self {
+        match error {
+            UpdateTaskStatusError::TaskNotFound() => {
+                AppError::not_found("task_not_found", error.to_string())
+            }
+            UpdateTaskStatusError::Repository(e) => e.into(),
+        }
+    }
 }
 
 impl fmt::Display for UpdateTaskStatusError {
@@ -43,9 +75,7 @@ impl fmt::Display for UpdateTaskStatusError {
             UpdateTaskStatusError::TaskNotFound() => {
                 write!(f, "Task not found in database")
             }
-            UpdateTaskStatusError::RequestFailed(e) => {
-                write!(f, "Request to signed URL API failed: {}", e)
-            }
+            UpdateTaskStatusError::Repository(e) => write!(f, "{}", e),
         }
     }
 }
@@ -59,28 +89,31 @@ impl UpdateTaskStatusUseCase {
         Self { task_repository }
     }
 
+    /// Moves the task one step forward (`next`) or back (`previous`). Returns
+    /// `None` when nothing changes: the task is already at the end the action
+    /// moves it towards, or the action is unknown.
     pub async fn execute(
         &self,
         request: UpdateTaskStatusRequest,
-    ) -> Result<(), UpdateTaskStatusError> {
+    ) -> Result<Option<TaskStatusTransition>, UpdateTaskStatusError> {
         let task = self
             .task_repository
             .find_by_id(request.task_id.clone())
-            .await;
+            .await?;
 
         if task.is_none() {
             return Err(UpdateTaskStatusError::TaskNotFound());
         }
         let task = task.unwrap();
-        if task.user_id() != request.user_id {
+        if task.user_id() != request.user_id || task.is_deleted() {
             return Err(UpdateTaskStatusError::TaskNotFound());
         }
         let new_status = {
             if *task.status() == Completed && request.action == "next" {
-                return Ok(());
+                return Ok(None);
             }
             if *task.status() == ToStart && request.action == "previous" {
-                return Ok(());
+                return Ok(None);
             }
             if *task.status() == ToStart && request.action == "next" {
                 Started
@@ -91,13 +124,16 @@ impl UpdateTaskStatusUseCase {
             } else if *task.status() == Completed && request.action == "previous" {
                 Started
             } else {
-                return Ok(());
+                return Ok(None);
             }
         };
 
         self.task_repository
             .update_status(request.user_id, request.task_id, new_status)
-            .await;
-        Ok(())
+            .await?;
+        Ok(Some(TaskStatusTransition {
+            from: *task.status(),
+            to: new_status,
+        }))
     }
 }
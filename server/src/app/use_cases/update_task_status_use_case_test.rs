@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::app::entities::task::Task;
+    use crate::app::entities::task_status::TaskStatus;
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::use_cases::update_task_status_use_case::{
+        TaskStatusTransition, UpdateTaskStatusRequest, UpdateTaskStatusUseCase,
+    };
+
+    #[actix_web::test]
+    async fn test_execute_should_return_the_applied_transition() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        let task_id = mock_repo.add_task(task);
+        let use_case = UpdateTaskStatusUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(UpdateTaskStatusRequest::new(
+                "user-1".to_string(),
+                task_id.clone(),
+                "next".to_string(),
+            ))
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            Some(TaskStatusTransition {
+                from: TaskStatus::ToStart,
+                to: TaskStatus::Started,
+            })
+        );
+        assert_eq!(
+            *mock_repo.find_task(&task_id).unwrap().status(),
+            TaskStatus::Started
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_at_first_status_going_back_should_change_nothing() {
+        // Arrange
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new("user-1".to_string(), "Buy milk".to_string(), Utc::now());
+        let task_id = mock_repo.add_task(task);
+        let use_case = UpdateTaskStatusUseCase::new(mock_repo.clone());
+
+        // Act
+        let result = use_case
+            .execute(UpdateTaskStatusRequest::new(
+                "user-1".to_string(),
+                task_id.clone(),
+                "previous".to_string(),
+            ))
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), None);
+        assert_eq!(
+            *mock_repo.find_task(&task_id).unwrap().status(),
+            TaskStatus::ToStart
+        );
+    }
+}
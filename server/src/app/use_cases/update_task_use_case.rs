@@ -0,0 +1,101 @@
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::app::{
+    errors::app_error::AppError,
+    repositories::{repository_error::RepositoryError, task_repository::TaskRepository},
+};
+
+pub struct UpdateTaskRequest {
+    user_id: String,
+    task_id: String,
+    content: Option<String>,
+    task_date: Option<DateTime<Utc>>,
+}
+
+impl UpdateTaskRequest {
+    pub fn new(
+        user_id: String,
+        task_id: String,
+        content: Option<String>,
+        task_date: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            user_id,
+            task_id,
+            content,
+            task_date,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum UpdateTaskError {
+    TaskNotFound(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for UpdateTaskError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => UpdateTaskError::TaskNotFound(),
+            error => UpdateTaskError::Repository(error),
+        }
+    }
+}
+
+impl From<UpdateTaskError> for AppError {
+    fn from(error: UpdateTaskError) -> Self {
+        match error {
+            UpdateTaskError::TaskNotFound() => {
+                AppError::not_found("task_not_found", error.to_string())
+            }
+            UpdateTaskError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateTaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateTaskError::TaskNotFound() => {
+                write!(f, "Task not found in database")
+            }
+            UpdateTaskError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct UpdateTaskUseCase {
+    task_repository: Arc<dyn TaskRepository + Send + Sync>,
+}
+
+impl UpdateTaskUseCase {
+    pub fn new(task_repository: Arc<dyn TaskRepository + Send + Sync>) -> Self {
+        Self { task_repository }
+    }
+
+    pub async fn execute(&self, request: UpdateTaskRequest) -> Result<(), UpdateTaskError> {
+        let task = self
+            .task_repository
+            .find_by_id(request.task_id.clone())
+            .await?;
+
+        let mut task = match task {
+            Some(task) if task.user_id() == request.user_id && !task.is_deleted() => task,
+            _ => return Err(UpdateTaskError::TaskNotFound()),
+        };
+
+        if let Some(content) = request.content {
+            task.set_content(content);
+        }
+        if let Some(task_date) = request.task_date {
+            task.set_task_date(task_date);
+        }
+
+        self.task_repository.update(task).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+
+    use crate::app::entities::task::Task;
+    use crate::app::repositories::mock_task_repository::MockTaskRepository;
+    use crate::app::use_cases::update_task_use_case::{
+        UpdateTaskError, UpdateTaskRequest, UpdateTaskUseCase,
+    };
+
+    fn setup() -> (Arc<MockTaskRepository>, String) {
+        let mock_repo = Arc::new(MockTaskRepository::new());
+        let task = Task::new(
+            "user-1".to_string(),
+            "Buy milk".to_string(),
+            Utc.with_ymd_and_hms(2025, 10, 1, 9, 0, 0).unwrap(),
+        );
+        let task_id = mock_repo.add_task(task);
+        (mock_repo, task_id)
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_update_content_and_task_date() {
+        // Arrange
+        let (mock_repo, task_id) = setup();
+        let use_case = UpdateTaskUseCase::new(mock_repo.clone());
+        let new_date = Utc.with_ymd_and_hms(2025, 10, 2, 18, 30, 0).unwrap();
+        let request = UpdateTaskRequest::new(
+            "user-1".to_string(),
+            task_id.clone(),
+            Some("Buy oat milk".to_string()),
+            Some(new_date),
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let task = mock_repo.find_task(&task_id).unwrap();
+        assert_eq!(task.content(), "Buy oat milk");
+        assert_eq!(*task.task_date(), new_date);
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_keep_fields_that_were_not_sent() {
+        // Arrange
+        let (mock_repo, task_id) = setup();
+        let use_case = UpdateTaskUseCase::new(mock_repo.clone());
+        let request = UpdateTaskRequest::new(
+            "user-1".to_string(),
+            task_id.clone(),
+            Some("Buy bread".to_string()),
+            None,
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let task = mock_repo.find_task(&task_id).unwrap();
+        assert_eq!(task.content(), "Buy bread");
+        assert_eq!(
+            *task.task_date(),
+            Utc.with_ymd_and_hms(2025, 10, 1, 9, 0, 0).unwrap()
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_task_of_another_user_should_return_not_found() {
+        // Arrange
+        let (mock_repo, task_id) = setup();
+        let use_case = UpdateTaskUseCase::new(mock_repo.clone());
+        let request = UpdateTaskRequest::new(
+            "user-2".to_string(),
+            task_id.clone(),
+            Some("Hijacked".to_string()),
+            None,
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(matches!(result, Err(UpdateTaskError::TaskNotFound())));
+        let task = mock_repo.find_task(&task_id).unwrap();
+        assert_eq!(task.content(), "Buy milk");
+    }
+}
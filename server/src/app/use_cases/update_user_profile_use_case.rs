@@ -0,0 +1,159 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::app::{
+    entities::user::User,
+    errors::app_error::{AppError, InvalidParam},
+    repositories::{repository_error::RepositoryError, user_repository::UserRepository},
+    use_cases::{
+        password_hashing::verify_password,
+        user_validation::{normalize_email, validate_email, validate_username},
+    },
+};
+
+pub struct UpdateUserProfileRequest {
+    user_id: String,
+    username: Option<String>,
+    email: Option<String>,
+    current_password: Option<String>,
+}
+
+impl UpdateUserProfileRequest {
+    pub fn new(
+        user_id: String,
+        username: Option<String>,
+        email: Option<String>,
+        current_password: Option<String>,
+    ) -> Self {
+        Self {
+            user_id,
+            username,
+            email,
+            current_password,
+        }
+    }
+}
+
+pub struct UpdateUserProfileResponse {
+    pub user: User,
+}
+
+#[derive(Debug)]
+pub enum UpdateUserProfileError {
+    UserNotFound(),
+    Validation(Vec<InvalidParam>),
+    InvalidCurrentPassword(),
+    EmailAlreadyRegistered(),
+    Repository(RepositoryError),
+}
+
+impl From<RepositoryError> for UpdateUserProfileError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => UpdateUserProfileError::UserNotFound(),
+            RepositoryError::Conflict(_) => UpdateUserProfileError::EmailAlreadyRegistered(),
+            error => UpdateUserProfileError::Repository(error),
+        }
+    }
+}
+
+impl From<UpdateUserProfileError> for AppError {
+    fn from(error: UpdateUserProfileError) -> Self {
+        match error {
+            UpdateUserProfileError::UserNotFound() => {
+                AppError::not_found("user_not_found", error.to_string())
+            }
+            UpdateUserProfileError::Validation(invalid_params) => {
+                AppError::unprocessable("validation_failed", "One or more fields are invalid")
+                    .with_invalid_params(invalid_params)
+            }
+            UpdateUserProfileError::InvalidCurrentPassword() => {
+                AppError::forbidden("invalid_current_password", error.to_string())
+            }
+            UpdateUserProfileError::EmailAlreadyRegistered() => {
+                AppError::conflict("email_already_registered", error.to_string())
+            }
+            UpdateUserProfileError::Repository(e) => e.into(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateUserProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateUserProfileError::UserNotFound() => write!(f, "User not found"),
+            UpdateUserProfileError::Validation(invalid_params) => {
+                let fields: Vec<&str> = invalid_params.iter().map(|param| param.name).collect();
+                write!(f, "Invalid fields: {}", fields.join(", "))
+            }
+            UpdateUserProfileError::InvalidCurrentPassword() => {
+                write!(f, "The current password is incorrect")
+            }
+            UpdateUserProfileError::EmailAlreadyRegistered() => {
+                write!(f, "A user with this email already exists")
+            }
+            UpdateUserProfileError::Repository(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub struct UpdateUserProfileUseCase {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+}
+
+impl UpdateUserProfileUseCase {
+    pub fn new(user_repository: Arc<dyn UserRepository + Send + Sync>) -> Self {
+        Self { user_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        request: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResponse, UpdateUserProfileError> {
+        let mut user = self
+            .user_repository
+            .get_user_by_id(request.user_id)
+            .await?
+            .ok_or(UpdateUserProfileError::UserNotFound())?;
+
+        let username = request.username;
+        let email = request.email.as_deref().map(normalize_email);
+        let email_changed = email.as_deref().is_some_and(|email| email != user.email());
+
+        let mut invalid_params: Vec<InvalidParam> = [
+            username.as_deref().and_then(validate_username),
+            email.as_deref().and_then(validate_email),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if email_changed && request.current_password.is_none() {
+            invalid_params.push(InvalidParam::new(
+                "current_password",
+                "is required to change the email",
+            ));
+        }
+        if !invalid_params.is_empty() {
+            return Err(UpdateUserProfileError::Validation(invalid_params));
+        }
+
+        // Whoever controls the email can reset the password, so moving it to
+        // another address needs proof that the caller is the account owner.
+        if email_changed
+            && let Some(current_password) = &request.current_password
+            && !verify_password(current_password, user.password())
+        {
+            return Err(UpdateUserProfileError::InvalidCurrentPassword());
+        }
+
+        if let Some(username) = username {
+            user.set_username(username);
+        }
+        if let Some(email) = email {
+            user.set_email(email);
+        }
+
+        self.user_repository.update_profile(user.clone()).await?;
+        Ok(UpdateUserProfileResponse { user })
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::app::entities::user::User;
+    use crate::app::repositories::mock_user_repository::MockUserRepository;
+    use crate::app::use_cases::update_user_profile_use_case::{
+        UpdateUserProfileError, UpdateUserProfileRequest, UpdateUserProfileUseCase,
+    };
+
+    fn setup() -> (String, Arc<MockUserRepository>, UpdateUserProfileUseCase) {
+        let user_repo = Arc::new(MockUserRepository::new());
+        user_repo.add_user(User::new(
+            "test@example.com".to_string(),
+            "testuser".to_string(),
+            "password123".to_string(),
+        ));
+        user_repo.add_user(User::new(
+            "other@example.com".to_string(),
+            "other".to_string(),
+            "password123".to_string(),
+        ));
+        let user_id = user_repo
+            .find_user_by_email("test@example.com")
+            .unwrap()
+            .id()
+            .unwrap()
+            .clone();
+        let use_case = UpdateUserProfileUseCase::new(user_repo.clone());
+        (user_id, user_repo, use_case)
+    }
+
+    #[actix_web::test]
+    async fn test_execute_should_update_username_without_password() {
+        // Arrange
+        let (user_id, user_repo, use_case) = setup();
+        let request =
+            UpdateUserProfileRequest::new(user_id, Some("renamed".to_string()), None, None);
+
+        // Act
+        let response = use_case.execute(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.user.username(), "renamed");
+        let user = user_repo.find_user_by_email("test@example.com").unwrap();
+        assert_eq!(user.username(), "renamed");
+    }
+
+    #[actix_web::test]
+    async fn test_execute_changing_email_should_require_current_password() {
+        // Arrange
+        let (user_id, _, use_case) = setup();
+        let missing = UpdateUserProfileRequest::new(
+            user_id.clone(),
+            None,
+            Some("new@example.com".to_string()),
+            None,
+        );
+        let wrong = UpdateUserProfileRequest::new(
+            user_id,
+            None,
+            Some("new@example.com".to_string()),
+            Some("wrong-password".to_string()),
+        );
+
+        // Act
+        let missing_result = use_case.execute(missing).await;
+        let wrong_result = use_case.execute(wrong).await;
+
+        // Assert
+        assert!(matches!(
+            missing_result,
+            Err(UpdateUserProfileError::Validation(_))
+        ));
+        assert!(matches!(
+            wrong_result,
+            Err(UpdateUserProfileError::InvalidCurrentPassword())
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_execute_with_taken_email_should_conflict() {
+        // Arrange
+        let (user_id, user_repo, use_case) = setup();
+        let request = UpdateUserProfileRequest::new(
+            user_id,
+            None,
+            Some("OTHER@example.com".to_string()),
+            Some("password123".to_string()),
+        );
+
+        // Act
+        let result = use_case.execute(request).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(UpdateUserProfileError::EmailAlreadyRegistered())
+        ));
+        assert!(user_repo.find_user_by_email("test@example.com").is_some());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::app::errors::app_error::InvalidParam;
+
+/// Matches `users.username VARCHAR(60)`.
+pub const USERNAME_MAX_LENGTH: usize = 60;
+/// Matches `users.email VARCHAR(255)`.
+pub const EMAIL_MAX_LENGTH: usize = 255;
+pub const PASSWORD_MIN_LENGTH: usize = 8;
+pub const PASSWORD_MAX_LENGTH: usize = 128;
+
+/// Trims surrounding whitespace so `" a@b.c"` and `"a@b.c"` are the same
+/// account. Case is preserved; uniqueness is enforced on `LOWER(email)`.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_string()
+}
+
+pub fn validate_email(email: &str) -> Option<InvalidParam> {
+    let reason = if email.is_empty() {
+        "must not be empty".to_string()
+    } else if email.chars().count() > EMAIL_MAX_LENGTH {
+        format!("must be at most {EMAIL_MAX_LENGTH} characters")
+    } else if !is_email_shaped(email) {
+        "must be a valid email address".to_string()
+    } else {
+        return None;
+    };
+    Some(InvalidParam::new("email", reason))
+}
+
+pub fn validate_username(username: &str) -> Option<InvalidParam> {
+    let reason = if username.trim().is_empty() {
+        "must not be empty".to_string()
+    } else if username.chars().count() > USERNAME_MAX_LENGTH {
+        format!("must be at most {USERNAME_MAX_LENGTH} characters")
+    } else {
+        return None;
+    };
+    Some(InvalidParam::new("username", reason))
+}
+
+/// A password needs a minimum length and at least two character classes out
+/// of lowercase, uppercase, digits and symbols.
+pub fn validate_password(name: &'static str, password: &str) -> Option<InvalidParam> {
+    let length = password.chars().count();
+    let classes = [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count();
+
+    let reason = if length < PASSWORD_MIN_LENGTH {
+        format!("must be at least {PASSWORD_MIN_LENGTH} characters")
+    } else if length > PASSWORD_MAX_LENGTH {
+        format!("must be at most {PASSWORD_MAX_LENGTH} characters")
+    } else if classes < 2 {
+        "must mix at least two of lowercase, uppercase, digits and symbols".to_string()
+    } else {
+        return None;
+    };
+    Some(InvalidParam::new(name, reason))
+}
+
+fn is_email_shaped(email: &str) -> bool {
+    if email.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain
+            .split('.')
+            .all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+        && domain.contains('.')
+}
@@ -0,0 +1,120 @@
+use std::{fmt, sync::Arc};
+
+use sqlx::{PgPool, migrate::MigrateError};
+
+use crate::{
+    app::use_cases::backfill_identicons_use_case::{
+        BackfillIdenticonsError, BackfillIdenticonsRequest, BackfillIdenticonsUseCase,
+    },
+    infra::{
+        db::{
+            migrations::{migration_status, revert_last_migration, run_pending_migrations},
+            sqlx_repository::SqlxRepository,
+        },
+        storage::configuration::get_storage_backend,
+    },
+    settings::Settings,
+};
+
+pub const USAGE: &str = "Usage: server [migrate <up|status|revert> | avatars backfill-identicons]";
+
+/// What the binary was asked to do; serving is the default.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Serve,
+    Migrate(MigrateCommand),
+    /// Replaces the dangling `{DEFAULT_AVATAR}-{uuid}` avatars of older
+    /// accounts with identicons.
+    BackfillIdenticons,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MigrateCommand {
+    /// Applies every pending migration.
+    Up,
+    /// Lists the migrations and whether each one has been applied.
+    Status,
+    /// Reverts the most recently applied migration.
+    Revert,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnknownCommand(pub String);
+
+impl fmt::Display for UnknownCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown command `{}`\n{}", self.0, USAGE)
+    }
+}
+
+impl Command {
+    /// Parses the arguments after the program name.
+    pub fn parse(args: &[String]) -> Result<Self, UnknownCommand> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        match args.as_slice() {
+            [] => Ok(Command::Serve),
+            ["migrate", "up"] => Ok(Command::Migrate(MigrateCommand::Up)),
+            ["migrate", "status"] => Ok(Command::Migrate(MigrateCommand::Status)),
+            ["migrate", "revert"] => Ok(Command::Migrate(MigrateCommand::Revert)),
+            ["avatars", "backfill-identicons"] => Ok(Command::BackfillIdenticons),
+            _ => Err(UnknownCommand(args.join(" "))),
+        }
+    }
+}
+
+/// Runs a `migrate` subcommand, reporting what it did on stdout.
+pub async fn run_migrate_command(
+    command: MigrateCommand,
+    pool: &PgPool,
+) -> Result<(), MigrateError> {
+    match command {
+        MigrateCommand::Up => {
+            let applied = run_pending_migrations(pool).await?;
+            if applied.is_empty() {
+                println!("No pending migrations");
+            }
+            for migration in applied {
+                println!("Applied {} {}", migration.version, migration.description);
+            }
+        }
+        MigrateCommand::Status => {
+            for status in migration_status(pool).await? {
+                let state = if status.applied { "applied" } else { "pending" };
+                println!(
+                    "{} {:<7} {}",
+                    status.migration.version, state, status.migration.description
+                );
+            }
+        }
+        MigrateCommand::Revert => match revert_last_migration(pool).await? {
+            Some(migration) => {
+                println!("Reverted {} {}", migration.version, migration.description)
+            }
+            None => println!("No applied migrations to revert"),
+        },
+    }
+    Ok(())
+}
+
+/// Runs `avatars backfill-identicons`, reporting what it did on stdout.
+pub async fn run_backfill_identicons_command(
+    settings: &Settings,
+    pool: &PgPool,
+) -> Result<(), BackfillIdenticonsError> {
+    let Some(default_avatar) = settings.default_avatar.clone() else {
+        println!("DEFAULT_AVATAR is not set, so no avatar can be a legacy default");
+        return Ok(());
+    };
+    let use_case = BackfillIdenticonsUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        get_storage_backend(&settings.storage).object_storage(),
+    );
+    let response = use_case
+        .execute(BackfillIdenticonsRequest::new(default_avatar))
+        .await?;
+    println!(
+        "Replaced {} legacy default avatars with identicons, kept {} that were uploaded",
+        response.replaced, response.kept
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::command::{Command, MigrateCommand, UnknownCommand};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_should_read_subcommands() {
+        assert_eq!(Command::parse(&args(&[])), Ok(Command::Serve));
+        assert_eq!(
+            Command::parse(&args(&["migrate", "up"])),
+            Ok(Command::Migrate(MigrateCommand::Up))
+        );
+        assert_eq!(
+            Command::parse(&args(&["migrate", "status"])),
+            Ok(Command::Migrate(MigrateCommand::Status))
+        );
+        assert_eq!(
+            Command::parse(&args(&["migrate", "revert"])),
+            Ok(Command::Migrate(MigrateCommand::Revert))
+        );
+        assert_eq!(
+            Command::parse(&args(&["avatars", "backfill-identicons"])),
+            Ok(Command::BackfillIdenticons)
+        );
+    }
+
+    #[test]
+    fn test_parse_with_unknown_arguments_should_return_error() {
+        assert_eq!(
+            Command::parse(&args(&["migrate", "down"])),
+            Err(UnknownCommand("migrate down".to_string()))
+        );
+        assert_eq!(
+            Command::parse(&args(&["migrate"])),
+            Err(UnknownCommand("migrate".to_string()))
+        );
+    }
+}
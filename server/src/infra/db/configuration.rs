@@ -1,13 +1,46 @@
+use std::time::Duration;
+
 use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
 
-use crate::env::get_env_var;
+use crate::settings::DatabaseSettings;
+
+pub struct DatabaseConfiguration {
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout: Duration,
+    pub idle_timeout: Option<Duration>,
+    pub max_lifetime: Option<Duration>,
+}
+
+impl DatabaseConfiguration {
+    pub fn from_settings(settings: &DatabaseSettings) -> Self {
+        Self {
+            url: settings.url.clone(),
+            max_connections: settings.max_connections,
+            min_connections: settings.min_connections,
+            acquire_timeout: Duration::from_secs(settings.acquire_timeout_secs),
+            // A value of 0 disables the limit.
+            idle_timeout: seconds_or_none(settings.idle_timeout_secs),
+            max_lifetime: seconds_or_none(settings.max_lifetime_secs),
+        }
+    }
+}
 
-pub async fn get_configuration() -> Result<Pool<Postgres>, sqlx::Error> {
-    let database_url = get_env_var("DATABASE_URL").expect("DATABASE_URL must be set");
+fn seconds_or_none(seconds: u64) -> Option<Duration> {
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
 
+pub async fn get_configuration(
+    configuration: &DatabaseConfiguration,
+) -> Result<Pool<Postgres>, sqlx::Error> {
     let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
+        .max_connections(configuration.max_connections)
+        .min_connections(configuration.min_connections)
+        .acquire_timeout(configuration.acquire_timeout)
+        .idle_timeout(configuration.idle_timeout)
+        .max_lifetime(configuration.max_lifetime)
+        .connect(&configuration.url)
         .await?;
 
     Ok(pool)
@@ -1 +1,2 @@
+pub mod sqlx_error_mapper;
 pub mod sqlx_task_mapper;
@@ -0,0 +1,40 @@
+use crate::app::repositories::repository_error::RepositoryError;
+
+// SQLSTATE codes, see https://www.postgresql.org/docs/current/errcodes-appendix.html
+const UNIQUE_VIOLATION: &str = "23505";
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+const NOT_NULL_VIOLATION: &str = "23502";
+const CHECK_VIOLATION: &str = "23514";
+const STRING_DATA_RIGHT_TRUNCATION: &str = "22001";
+const INVALID_TEXT_REPRESENTATION: &str = "22P02";
+
+impl From<sqlx::Error> for RepositoryError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => RepositoryError::NotFound(error.to_string()),
+            sqlx::Error::Database(ref db_error) => match db_error.code().as_deref() {
+                Some(UNIQUE_VIOLATION) => RepositoryError::Conflict(db_error.to_string()),
+                Some(FOREIGN_KEY_VIOLATION)
+                | Some(NOT_NULL_VIOLATION)
+                | Some(CHECK_VIOLATION)
+                | Some(STRING_DATA_RIGHT_TRUNCATION)
+                | Some(INVALID_TEXT_REPRESENTATION) => {
+                    RepositoryError::ConstraintViolation(db_error.to_string())
+                }
+                _ => RepositoryError::Unexpected(db_error.to_string()),
+            },
+            sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed => RepositoryError::Unavailable(error.to_string()),
+            _ => RepositoryError::Unexpected(error.to_string()),
+        }
+    }
+}
+
+impl From<uuid::Error> for RepositoryError {
+    fn from(error: uuid::Error) -> Self {
+        RepositoryError::NotFound(format!("invalid id: {}", error))
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, Local, Timelike, Utc};
+use chrono::{DateTime, Datelike, Timelike, Utc};
 use chrono::{TimeZone, offset::LocalResult};
 use sqlx::types::time::PrimitiveDateTime;
 use sqlx::types::time::{Date, Time};
@@ -20,15 +20,22 @@ pub fn to_domain(date: PrimitiveDateTime) -> LocalResult<DateTime<Utc>> {
         November => 11,
         December => 12,
     };
-    let new_date = Utc.with_ymd_and_hms(
+
+    // Keep the sub-second part so keyset cursors built from these values
+    // compare exactly against the stored timestamps.
+    Utc.with_ymd_and_hms(
         date.year(),
         month,
         date.day() as u32,
         date.hour() as u32,
         date.minute() as u32,
         date.second() as u32,
-    );
-    new_date
+    )
+    .map(|new_date| {
+        new_date
+            .with_nanosecond(date.nanosecond())
+            .unwrap_or(new_date)
+    })
 }
 
 pub fn chrono_to_primitive(datetime: DateTime<Utc>) -> PrimitiveDateTime {
@@ -49,11 +56,11 @@ pub fn chrono_to_primitive(datetime: DateTime<Utc>) -> PrimitiveDateTime {
 
     let date = Date::from_calendar_date(datetime.year(), month, datetime.day() as u8).unwrap();
 
-    let time = Time::from_hms_milli(
+    let time = Time::from_hms_micro(
         datetime.hour() as u8,
         datetime.minute() as u8,
         datetime.second() as u8,
-        datetime.timestamp_subsec_millis() as u16,
+        datetime.timestamp_subsec_micros(),
     )
     .unwrap();
 
@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use sqlx::{
+    PgPool,
+    migrate::{Migrate, MigrateError, Migration, Migrator},
+};
+
+/// The scripts in `migrations/`, embedded at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Migrations whose script changed after they were released, with the hex
+/// SHA-384 checksum of the original script. They used to start with
+/// `DROP TABLE IF EXISTS` and now leave existing tables alone. Databases that
+/// applied the original get the new checksum recorded instead of failing the
+/// checksum validation.
+pub const REWRITTEN_MIGRATIONS: &[(i64, &str)] = &[
+    (
+        20250812203823,
+        "416c314109e7e95e5a43fc6ea06a76e6508dc69a4ca4a97bd5f389e8ba4b9b089bc131c78b00feb3076daf1e787aba9e",
+    ),
+    (
+        20250819020438,
+        "79141facf30b2b9793629dc55de82f09b617a7542b384a96cc3d4d8c07729e8c70bf8aca80ab2019a1e3e5b9c06992f5",
+    ),
+    (
+        20251003150518,
+        "35e251e9d9acd1233d1d5a8ccf236b9d03b09a6114c2076735ea2650e87afe139d4bc772521323b43d1637d57c567db2",
+    ),
+];
+
+pub struct MigrationStatus {
+    pub migration: &'static Migration,
+    pub applied: bool,
+}
+
+fn up_migrations() -> impl Iterator<Item = &'static Migration> {
+    MIGRATOR
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+}
+
+async fn applied_versions(pool: &PgPool) -> Result<BTreeSet<i64>, MigrateError> {
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    Ok(conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|applied| applied.version)
+        .collect())
+}
+
+async fn update_rewritten_checksums(pool: &PgPool) -> Result<(), MigrateError> {
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    for (version, original_checksum) in REWRITTEN_MIGRATIONS {
+        let Some(migration) = up_migrations().find(|migration| migration.version == *version)
+        else {
+            continue;
+        };
+        let original_checksum =
+            hex::decode(original_checksum).expect("rewritten migration checksums are valid hex");
+        sqlx::query(
+            "UPDATE _sqlx_migrations SET checksum = $1 WHERE version = $2 AND checksum = $3",
+        )
+        .bind(migration.checksum.as_ref())
+        .bind(version)
+        .bind(original_checksum)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Every known migration and whether it has been applied.
+pub async fn migration_status(pool: &PgPool) -> Result<Vec<MigrationStatus>, MigrateError> {
+    let applied = applied_versions(pool).await?;
+    Ok(up_migrations()
+        .map(|migration| MigrationStatus {
+            migration,
+            applied: applied.contains(&migration.version),
+        })
+        .collect())
+}
+
+/// Migrations not applied yet. Unlike `migration_status`, this never creates
+/// the migrations table, so it is safe to call from probes.
+pub async fn pending_migrations(pool: &PgPool) -> Result<Vec<&'static Migration>, MigrateError> {
+    let mut conn = pool.acquire().await?;
+    let applied: BTreeSet<i64> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|applied| applied.version)
+        .collect();
+    Ok(up_migrations()
+        .filter(|migration| !applied.contains(&migration.version))
+        .collect())
+}
+
+/// Applies the pending migrations in order and returns them. Each runs in
+/// its own transaction, so a failing one leaves the database as it was
+/// after the previous one.
+pub async fn run_pending_migrations(
+    pool: &PgPool,
+) -> Result<Vec<&'static Migration>, MigrateError> {
+    update_rewritten_checksums(pool).await?;
+    let applied = applied_versions(pool).await?;
+    MIGRATOR.run(pool).await?;
+    Ok(up_migrations()
+        .filter(|migration| !applied.contains(&migration.version))
+        .collect())
+}
+
+/// Reverts the most recently applied migration and returns it, or `None`
+/// when nothing has been applied.
+pub async fn revert_last_migration(
+    pool: &PgPool,
+) -> Result<Option<&'static Migration>, MigrateError> {
+    update_rewritten_checksums(pool).await?;
+    let applied = applied_versions(pool).await?;
+    let mut latest_first = applied.iter().rev();
+    let Some(&latest) = latest_first.next() else {
+        return Ok(None);
+    };
+    // `undo` reverts everything applied after the target version.
+    let target = latest_first.next().copied().unwrap_or(0);
+    MIGRATOR.undo(pool, target).await?;
+    Ok(up_migrations().find(|migration| migration.version == latest))
+}
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::infra::db::migrations::{MIGRATOR, REWRITTEN_MIGRATIONS};
+
+    #[test]
+    fn test_up_migrations_should_never_drop_tables() {
+        for migration in MIGRATOR
+            .iter()
+            .filter(|migration| migration.migration_type.is_up_migration())
+        {
+            assert!(
+                !migration.sql.to_uppercase().contains("DROP TABLE"),
+                "migration {} drops a table",
+                migration.version
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_up_migration_should_have_a_down_migration() {
+        for up in MIGRATOR
+            .iter()
+            .filter(|migration| migration.migration_type.is_up_migration())
+        {
+            assert!(
+                MIGRATOR
+                    .iter()
+                    .any(|down| down.version == up.version
+                        && down.migration_type.is_down_migration()),
+                "migration {} cannot be reverted",
+                up.version
+            );
+        }
+    }
+
+    #[test]
+    fn test_rewritten_migrations_should_exist_with_a_new_checksum() {
+        for (version, original_checksum) in REWRITTEN_MIGRATIONS {
+            let migration = MIGRATOR
+                .iter()
+                .find(|migration| {
+                    migration.version == *version && migration.migration_type.is_up_migration()
+                })
+                .unwrap_or_else(|| panic!("migration {} is missing", version));
+            assert_ne!(hex::encode(migration.checksum.as_ref()), *original_checksum);
+        }
+    }
+}
@@ -1,3 +1,5 @@
 pub mod configuration;
 pub mod mappers;
+pub mod migrations;
+pub mod migrations_test;
 pub mod sqlx_repository;
@@ -1,20 +1,124 @@
-use actix_web::error;
+use sqlx::{PgPool, Postgres, QueryBuilder, types::time::PrimitiveDateTime};
+use uuid::Uuid;
 
 use crate::{
     app::{
-        entities::{task_status::TaskStatus, user::User},
+        entities::{
+            avatar::Avatar, password_reset_token::PasswordResetToken, session::Session, task::Task,
+            task_status::TaskStatus, user::User,
+        },
         repositories::{
-            task_repository::TaskRepository,
+            password_reset_repository::PasswordResetRepository,
+            repository_error::RepositoryError,
+            session_repository::SessionRepository,
+            task_repository::{SortDirection, TaskListQuery, TaskRepository, TaskSortField},
             user_repository::{UserProfile, UserRepository},
         },
     },
-    infra::db::{
-        configuration::get_configuration,
-        mappers::sqlx_task_mapper::{chrono_to_primitive, to_domain},
-    },
+    infra::db::mappers::sqlx_task_mapper::{chrono_to_primitive, to_domain},
 };
 
-pub struct SqlxRepository {}
+pub struct SqlxRepository {
+    pool: PgPool,
+}
+
+impl SqlxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct TaskRow {
+    id: Uuid,
+    user_id: Uuid,
+    content: String,
+    task_date: PrimitiveDateTime,
+    created_at: PrimitiveDateTime,
+    deleted_at: Option<PrimitiveDateTime>,
+    tasks_status: TaskStatus,
+}
+
+impl TaskRow {
+    fn into_domain(self) -> Task {
+        let created_at = to_domain(self.created_at)
+            .single()
+            .unwrap_or_else(chrono::Utc::now);
+        let task_date = to_domain(self.task_date)
+            .single()
+            .unwrap_or_else(chrono::Utc::now);
+
+        let mut task = Task::new_with_id(
+            self.id.to_string(),
+            self.user_id.to_string(),
+            self.content,
+            self.tasks_status,
+            task_date,
+            created_at,
+        );
+        task.set_deleted_at(self.deleted_at.and_then(|date| to_domain(date).single()));
+        task
+    }
+}
+
+struct SessionRow {
+    id: Uuid,
+    user_id: Uuid,
+    refresh_token_hash: String,
+    created_at: PrimitiveDateTime,
+    expires_at: PrimitiveDateTime,
+    revoked_at: Option<PrimitiveDateTime>,
+}
+
+impl SessionRow {
+    fn into_domain(self) -> Session {
+        let now = chrono::Utc::now();
+        Session::new_with_all(
+            self.id.to_string(),
+            self.user_id.to_string(),
+            self.refresh_token_hash,
+            to_domain(self.created_at).single().unwrap_or(now),
+            // An unreadable expiry is treated as already expired.
+            to_domain(self.expires_at).single().unwrap_or(now),
+            self.revoked_at.and_then(|date| to_domain(date).single()),
+        )
+    }
+}
+
+struct PasswordResetTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    token_hash: String,
+    created_at: PrimitiveDateTime,
+    expires_at: PrimitiveDateTime,
+    used_at: Option<PrimitiveDateTime>,
+}
+
+impl PasswordResetTokenRow {
+    fn into_domain(self) -> PasswordResetToken {
+        let now = chrono::Utc::now();
+        PasswordResetToken::new_with_all(
+            self.id.to_string(),
+            self.user_id.to_string(),
+            self.token_hash,
+            to_domain(self.created_at).single().unwrap_or(now),
+            to_domain(self.expires_at).single().unwrap_or(now),
+            self.used_at.and_then(|date| to_domain(date).single()),
+        )
+    }
+}
+
+// UPDATE and DELETE statements report success even when no row matched, so
+// callers use this to surface a missing task as `NotFound`.
+fn expect_affected(
+    result: sqlx::postgres::PgQueryResult,
+    task_id: &str,
+) -> Result<(), RepositoryError> {
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound(format!("task {}", task_id)));
+    }
+    Ok(())
+}
 
 impl UserRepository for SqlxRepository {
     fn register<'a>(
@@ -22,33 +126,23 @@ impl UserRepository for SqlxRepository {
         user: User,
         file_key: String,
         mime_type: String,
-    ) -> std::pin::Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
+            let db_conn = &self.pool;
 
-            let mut transaction = db_conn
-                .begin()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to start database transaction.")
-                })
-                .map_err(|e| eprint!("Failed to start a transaction: {}", e))
-                .unwrap();
+            let mut transaction = db_conn.begin().await?;
 
+            // Keeps an ID chosen by the caller, otherwise lets the database pick one.
+            let id = user.id().map(|id| Uuid::parse_str(id)).transpose()?;
             let new_user = sqlx::query!(
-            "INSERT INTO users (email, username, password_hash) VALUES ($1, $2, $3) RETURNING id",
-            user.email(),
-            user.username(),
-            user.password(),
-        )
-        .fetch_one(&mut *transaction)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to create user: {}", e);
-            error::ErrorInternalServerError("Failed to register user.")
-        }).unwrap();
-
-            let user_id = new_user.id;
+                "INSERT INTO users (id, email, username, password_hash) VALUES (COALESCE($1, gen_random_uuid()), $2, $3, $4) RETURNING id",
+                id as Option<Uuid>,
+                user.email(),
+                user.username(),
+                user.password(),
+            )
+            .fetch_one(&mut *transaction)
+            .await?;
 
             sqlx::query(
                 r#"
@@ -56,124 +150,247 @@ impl UserRepository for SqlxRepository {
         VALUES ($1, $2, $3)
         "#,
             )
-            .bind(user_id)
+            .bind(new_user.id)
             .bind(file_key)
             .bind(mime_type)
             .execute(&mut *transaction)
-            .await
-            .map_err(|e| {
-                eprintln!("Failed to create avatar: {}", e);
-                error::ErrorInternalServerError("Failed to save user avatar.")
-            })
-            .map_err(|e| eprintln!("Failed to create an avatar: {}", e))
-            .unwrap();
-
-            transaction
-                .commit()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to commit database transaction.")
-                })
-                .map_err(|e| eprintln!("Failed to commit the transaction: {}", e))
-                .unwrap()
+            .await?;
+
+            transaction.commit().await?;
+            Ok(())
         })
     }
 
     fn get_user_by_email<'a>(
         &'a self,
         email: String,
-    ) -> std::pin::Pin<Box<dyn Future<Output = Option<User>> + Send + 'a>> {
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Option<User>, RepositoryError>> + Send + 'a>>
+    {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
+            let db_conn = &self.pool;
+
+            let user = sqlx::query!("SELECT * FROM users WHERE LOWER(email) = LOWER($1)", email)
+                .fetch_optional(db_conn)
+                .await?;
+
+            Ok(user.map(|user| {
+                User::new_with_id(
+                    user.id.to_string(),
+                    user.email,
+                    user.username,
+                    user.password_hash,
+                )
+            }))
+        })
+    }
 
-            let mut transaction = db_conn
-                .begin()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to start database transaction.")
-                })
-                .map_err(|e| eprint!("Failed to start a transaction: {}", e))
-                .unwrap();
-
-            let user = sqlx::query!("SELECT * FROM users WHERE email = $1", email)
-                .fetch_one(&mut *transaction)
-                .await
-                .map_err(|e| {
-                    eprintln!("Failed to an user by email: {}", e);
-                    error::ErrorInternalServerError("Failed to get an user by email.")
-                })
-                .ok()?;
+    fn get_user_profile(
+        &self,
+        user_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Option<UserProfile>, RepositoryError>> + Send>>
+    {
+        // The returned future is not tied to `&self`, so it takes its own handle.
+        let db_conn = self.pool.clone();
 
-            transaction
-                .commit()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to commit database transaction.")
-                })
-                .map_err(|e| eprintln!("Failed to commit the transaction: {}", e))
-                .unwrap();
-
-            let user = User::new_with_id(
-                user.id.to_string(),
-                user.email,
-                user.username,
-                user.password_hash,
-            );
-            Some(user)
+        Box::pin(async move {
+            let user_profile = sqlx::query!(
+                "SELECT users.email, users.username, avatars.file_key, avatars.thumbnail_file_key FROM users INNER JOIN avatars ON avatars.user_id = users.id WHERE users.id = $1",
+                Uuid::parse_str(&user_id)?
+            )
+            .fetch_optional(&db_conn)
+            .await?;
+
+            Ok(user_profile.map(|user_profile| UserProfile {
+                user: User::new_without_password(user_profile.email, user_profile.username),
+                avatar_url: user_profile.file_key,
+                thumbnail_url: user_profile.thumbnail_file_key,
+            }))
+        })
+    }
+
+    fn get_user_by_id<'a>(
+        &'a self,
+        user_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Option<User>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+
+            let user = sqlx::query!(
+                "SELECT id, email, username, password_hash FROM users WHERE id = $1",
+                Uuid::parse_str(&user_id)?
+            )
+            .fetch_optional(db_conn)
+            .await?;
+
+            Ok(user.map(|user| {
+                User::new_with_id(
+                    user.id.to_string(),
+                    user.email,
+                    user.username,
+                    user.password_hash,
+                )
+            }))
         })
     }
 
-    fn get_user_profile<'a>(
+    fn update_profile<'a>(
+        &'a self,
+        user: User,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let user_id = user.id().cloned().unwrap_or_default();
+            let result = sqlx::query!(
+                "UPDATE users SET username = $1, email = $2 WHERE id = $3",
+                user.username(),
+                user.email(),
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!("user {}", user_id)));
+            }
+            Ok(())
+        })
+    }
+
+    fn update_password<'a>(
         &'a self,
         user_id: String,
-    ) -> std::pin::Pin<Box<dyn Future<Output = Option<UserProfile>> + Send>> {
+        password_hash: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let result = sqlx::query!(
+                "UPDATE users SET password_hash = $1 WHERE id = $2",
+                password_hash,
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!("user {}", user_id)));
+            }
+            Ok(())
+        })
+    }
+
+    fn list_avatars<'a>(
+        &'a self,
+        user_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Vec<Avatar>, RepositoryError>> + Send + 'a>>
+    {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
+            let db_conn = &self.pool;
+            let avatars = sqlx::query!(
+                "SELECT id, user_id, file_key, mime_type, thumbnail_file_key, created_at FROM avatars WHERE user_id = $1 ORDER BY created_at",
+                Uuid::parse_str(&user_id)?
+            )
+            .fetch_all(db_conn)
+            .await?;
 
-            let mut transaction = db_conn
-                .begin()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to start database transaction.")
+            Ok(avatars
+                .into_iter()
+                .map(|row| {
+                    let mut avatar = Avatar::new(
+                        row.id.to_string(),
+                        row.user_id.to_string(),
+                        row.file_key,
+                        row.mime_type,
+                    );
+                    avatar.set_thumbnail_file_key(row.thumbnail_file_key);
+                    avatar.set_created_at(row.created_at.and_then(|date| to_domain(date).single()));
+                    avatar
                 })
-                .map_err(|e| eprint!("Failed to start a transaction: {}", e))
-                .unwrap();
+                .collect())
+        })
+    }
 
-            let user_profile = sqlx::query!(
-                "SELECT users.email, users.username, avatars.file_key FROM users INNER JOIN avatars ON $1 = avatars.user_id",
-                uuid::Uuid::parse_str(&user_id).unwrap()
+    fn list_avatars_with_key_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Vec<Avatar>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            // `starts_with` rather than `LIKE`, so `%` and `_` in the prefix
+            // match themselves.
+            let avatars = sqlx::query!(
+                "SELECT id, user_id, file_key, mime_type, thumbnail_file_key, created_at FROM avatars WHERE starts_with(file_key, $1) ORDER BY created_at",
+                prefix
             )
-            .fetch_one(&mut *transaction)
-            .await
-            .map_err(|e| {
-                eprintln!("Failed to an user by email: {}", e);
-                error::ErrorInternalServerError("Failed to get an user by email.")
-            })
-            .ok();
-
-            match user_profile {
-                Some(user_profile) => {
-                    transaction
-                        .commit()
-                        .await
-                        .map_err(|_| {
-                            error::ErrorInternalServerError(
-                                "Failed to commit database transaction.",
-                            )
-                        })
-                        .map_err(|e| eprintln!("Failed to commit the transaction: {}", e))
-                        .unwrap();
-
-                    let user =
-                        User::new_without_password(user_profile.email, user_profile.username);
-                    let user_profile = UserProfile {
-                        user,
-                        avatar_url: user_profile.file_key,
-                    };
-                    Some(user_profile)
-                }
-                None => None,
+            .fetch_all(db_conn)
+            .await?;
+
+            Ok(avatars
+                .into_iter()
+                .map(|row| {
+                    let mut avatar = Avatar::new(
+                        row.id.to_string(),
+                        row.user_id.to_string(),
+                        row.file_key,
+                        row.mime_type,
+                    );
+                    avatar.set_thumbnail_file_key(row.thumbnail_file_key);
+                    avatar.set_created_at(row.created_at.and_then(|date| to_domain(date).single()));
+                    avatar
+                })
+                .collect())
+        })
+    }
+
+    fn update_avatar<'a>(
+        &'a self,
+        user_id: String,
+        file_key: String,
+        mime_type: String,
+        thumbnail_file_key: Option<String>,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let result = sqlx::query!(
+                "UPDATE avatars SET file_key = $1, mime_type = $2, thumbnail_file_key = $3 WHERE user_id = $4",
+                file_key,
+                mime_type,
+                thumbnail_file_key,
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!(
+                    "avatar of user {}",
+                    user_id
+                )));
             }
+            Ok(())
+        })
+    }
+
+    fn delete_user<'a>(
+        &'a self,
+        user_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            // Tasks, avatars, sessions and reset tokens go with the user
+            // through ON DELETE CASCADE.
+            let result = sqlx::query!(
+                "DELETE FROM users WHERE id = $1",
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!("user {}", user_id)));
+            }
+            Ok(())
         })
     }
 }
@@ -181,238 +398,126 @@ impl UserRepository for SqlxRepository {
 impl TaskRepository for SqlxRepository {
     fn save<'a>(
         &'a self,
-        task: crate::app::entities::task::Task,
-    ) -> std::pin::Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        task: Task,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
+            let db_conn = &self.pool;
+            let user_id = Uuid::parse_str(task.user_id())?;
 
-            let mut transaction = db_conn
-                .begin()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to start database transaction.")
-                })
-                .map_err(|e| eprint!("Failed to start a transaction: {}", e))
-                .unwrap();
+            let mut transaction = db_conn.begin().await?;
 
-            let user = sqlx::query!(
-                "SELECT * FROM users WHERE id = $1",
-                uuid::Uuid::parse_str(&task.user_id()).unwrap()
-            );
+            let user = sqlx::query!("SELECT id FROM users WHERE id = $1", user_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
 
-            if (user.fetch_one(&mut *transaction).await).is_err() {
-                transaction
-                    .rollback()
-                    .await
-                    .map_err(|_| {
-                        error::ErrorInternalServerError("Failed to rollback database transaction.")
-                    })
-                    .map_err(|e| eprintln!("Failed to rollback the transaction: {}", e))
-                    .unwrap();
-                return;
+            if user.is_none() {
+                transaction.rollback().await?;
+                return Err(RepositoryError::NotFound(format!("user {}", user_id)));
             }
 
-            let task_status = task.status();
-            let task_date = chrono_to_primitive(*task.task_date());
-
-            println!("Primitive Date time {:?}", task_date);
-
             sqlx::query!(
                 "INSERT INTO tasks (user_id, content, task_date ,tasks_status) VALUES ($1, $2, $3, $4)",
-                uuid::Uuid::parse_str(&task.user_id()).unwrap(),
+                user_id,
                 task.content(),
-                task_date,
-                task_status as _
+                chrono_to_primitive(*task.task_date()),
+                task.status() as _
             )
             .execute(&mut *transaction)
-            .await
-            .map_err(|e| {
-                eprintln!("Failed to create task: {}", e);
-                error::ErrorInternalServerError("Failed to save task.")
-            })
-            .map_err(|e| eprintln!("Failed to create a task: {}", e))
-            .unwrap();
-
-            transaction
-                .commit()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to commit database transaction.")
-                })
-                .map_err(|e| eprintln!("Failed to commit the transaction: {}", e))
-                .unwrap()
+            .await?;
+
+            transaction.commit().await?;
+            Ok(())
         })
     }
 
     fn list_all<'a>(
         &'a self,
         user_id: String,
-    ) -> std::pin::Pin<Box<dyn Future<Output = Vec<crate::app::entities::task::Task>> + Send + 'a>>
+        query: TaskListQuery,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Vec<Task>, RepositoryError>> + Send + 'a>>
     {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
+            let db_conn = &self.pool;
 
-            let mut transaction = db_conn
-                .begin()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to start database transaction.")
-                })
-                .map_err(|e| eprint!("Failed to start a transaction: {}", e))
-                .unwrap();
-
-            use uuid::Uuid;
-
-            // Convert user_id from String to Uuid
-            let user_uuid = match Uuid::parse_str(&user_id) {
-                Ok(uuid) => uuid,
-                Err(e) => {
-                    eprintln!("Invalid UUID: {}", e);
-                    transaction.rollback().await.ok();
-                    return Vec::new();
-                }
+            let mut builder = QueryBuilder::<Postgres>::new(
+                "SELECT id, user_id, content, task_date, created_at, deleted_at, tasks_status FROM tasks WHERE deleted_at IS NULL AND user_id = ",
+            );
+            builder.push_bind(Uuid::parse_str(&user_id)?);
+
+            if let Some(status) = query.status {
+                builder.push(" AND tasks_status = ").push_bind(status);
+            }
+            if let Some(task_date_from) = query.task_date_from {
+                builder
+                    .push(" AND task_date >= ")
+                    .push_bind(chrono_to_primitive(task_date_from));
+            }
+            if let Some(task_date_to) = query.task_date_to {
+                builder
+                    .push(" AND task_date <= ")
+                    .push_bind(chrono_to_primitive(task_date_to));
+            }
+
+            // Column and direction come from closed enums, never from raw input.
+            let column = match query.sort_by {
+                TaskSortField::TaskDate => "task_date",
+                TaskSortField::CreatedAt => "created_at",
+            };
+            let (comparison, direction) = match query.direction {
+                SortDirection::Asc => (">", "ASC"),
+                SortDirection::Desc => ("<", "DESC"),
             };
 
-            // Explicitly select columns and map tasks_status to an integer
-            let rows = sqlx::query!(
-                r#"
-                SELECT id, user_id, content, task_date, created_at ,tasks_status as "tasks_status: TaskStatus"
-                FROM tasks
-                WHERE user_id = $1
-                "#,
-                user_uuid
-            )
-            .fetch_all(&mut *transaction)
-            .await
-            .map_err(|e| {
-                eprintln!("Failed to fetch tasks: {}", e);
-                error::ErrorInternalServerError("Failed to list tasks.")
-            })
-            .map_err(|e| eprintln!("Failed to fetch tasks: {}", e))
-            .unwrap_or_default();
-
-            transaction
-                .commit()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to commit database transaction.")
-                })
-                .map_err(|e| eprintln!("Failed to commit the transaction: {}", e))
-                .unwrap();
+            if let Some(cursor) = query.after {
+                builder
+                    .push(format!(" AND ({column}, id) {comparison} ("))
+                    .push_bind(chrono_to_primitive(cursor.sort_value))
+                    .push(", ")
+                    .push_bind(Uuid::parse_str(&cursor.id)?)
+                    .push(")");
+            }
 
-            rows.into_iter()
-                .map(|row| {
-                    // let task_status;
-                    // match row.tasks_status {
-                    //     TaskStatus::ToStart => task_status = TaskStatus::ToStart,
-                    //     TaskStatus::Started => task_status = TaskStatus::Started,
-                    //     TaskStatus::Completed => task_status = TaskStatus::Completed,
-                    // }
-
-                    let created_at_utc = to_domain(row.created_at.unwrap());
-                    let task_date_utc = to_domain(row.task_date);
-
-                    // Convert LocalResult<chrono::DateTime<Utc>> to chrono::DateTime<Local>
-                    let created_at = match created_at_utc {
-                        chrono::LocalResult::Single(dt_utc) => dt_utc.with_timezone(&chrono::Utc),
-                        _ => chrono::Utc::now(), // fallback or handle error as needed
-                    };
-                    println!("created_at: {:?}", created_at);
-                    let task_date = match task_date_utc {
-                        chrono::LocalResult::Single(dt_utc) => dt_utc.with_timezone(&chrono::Utc),
-                        _ => chrono::Utc::now(), // fallback or handle error as needed
-                    };
-
-                    println!("Tasks_date: {:?}", task_date);
-
-                    crate::app::entities::task::Task::new_with_id(
-                        row.id.to_string(),
-                        row.user_id.to_string(),
-                        row.content,
-                        row.tasks_status,
-                        task_date,
-                        created_at,
-                    )
-                })
-                .collect()
+            builder
+                .push(format!(
+                    " ORDER BY {column} {direction}, id {direction} LIMIT "
+                ))
+                .push_bind(query.limit);
+
+            let rows = builder
+                .build_query_as::<TaskRow>()
+                .fetch_all(db_conn)
+                .await?;
+
+            Ok(rows.into_iter().map(TaskRow::into_domain).collect())
         })
     }
 
     fn find_by_id<'a>(
         &'a self,
         task_id: String,
-    ) -> std::pin::Pin<Box<dyn Future<Output = Option<crate::app::entities::task::Task>> + Send + 'a>>
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Option<Task>, RepositoryError>> + Send + 'a>>
     {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
+            let db_conn = &self.pool;
 
-            let mut transaction = db_conn
-                .begin()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to start database transaction.")
-                })
-                .map_err(|e| eprint!("Failed to start a transaction: {}", e))
-                .unwrap();
-
-            use uuid::Uuid;
-
-            let task_uuid = match Uuid::parse_str(&task_id) {
-                Ok(uuid) => uuid,
-                Err(e) => {
-                    eprintln!("Invalid UUID: {}", e);
-                    transaction.rollback().await.ok();
-                    return None;
-                }
+            // An id that is not a UUID cannot match any task.
+            let Ok(task_uuid) = Uuid::parse_str(&task_id) else {
+                return Ok(None);
             };
-            let row = sqlx::query!(
+
+            let row = sqlx::query_as!(
+                TaskRow,
                 r#"
-                SELECT id, user_id, content, task_date, created_at, tasks_status as "tasks_status: TaskStatus"
+                SELECT id, user_id, content, task_date, created_at, deleted_at, tasks_status as "tasks_status: TaskStatus"
                 FROM tasks
                 WHERE id = $1
                 "#,
                 task_uuid
             )
-            .fetch_optional(&mut *transaction)
-            .await
-            .map_err(|e| {
-                eprintln!("Failed to fetch task by ID: {}", e);
-                error::ErrorInternalServerError("Failed to find task by ID.")
-            })
-            .map_err(|e| eprintln!("Failed to fetch task by ID: {}", e))
-            .unwrap_or_default();
-
-            transaction
-                .commit()
-                .await
-                .map_err(|_| {
-                    error::ErrorInternalServerError("Failed to commit database transaction.")
-                })
-                .map_err(|e| eprintln!("Failed to commit the transaction: {}", e))
-                .unwrap();
-
-            row.map(|row| {
-                let created_at_utc = to_domain(row.created_at.unwrap());
-                let task_date_utc = to_domain(row.task_date);
-
-                let created_at = match created_at_utc {
-                    chrono::LocalResult::Single(dt_utc) => dt_utc.with_timezone(&chrono::Utc),
-                    _ => chrono::Utc::now(),
-                };
-                let task_date = match task_date_utc {
-                    chrono::LocalResult::Single(dt_utc) => dt_utc.with_timezone(&chrono::Utc),
-                    _ => chrono::Utc::now(),
-                };
-
-                crate::app::entities::task::Task::new_with_id(
-                    row.id.to_string(),
-                    row.user_id.to_string(),
-                    row.content,
-                    row.tasks_status,
-                    task_date,
-                    created_at,
-                )
-            })
+            .fetch_optional(db_conn)
+            .await?;
+
+            Ok(row.map(TaskRow::into_domain))
         })
     }
 
@@ -421,22 +526,391 @@ impl TaskRepository for SqlxRepository {
         user_id: String,
         task_id: String,
         status: TaskStatus,
-    ) -> std::pin::Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
-            let db_conn = get_configuration().await.unwrap();
-            sqlx::query!(
-                "UPDATE tasks SET tasks_status = $1 WHERE id = $2 AND user_id = $3",
+            let db_conn = &self.pool;
+            let result = sqlx::query!(
+                "UPDATE tasks SET tasks_status = $1 WHERE id = $2 AND user_id = $3 AND deleted_at IS NULL",
                 status as _,
-                uuid::Uuid::parse_str(&task_id).unwrap(),
-                uuid::Uuid::parse_str(&user_id).unwrap()
-            )
-            .execute(&db_conn)
-            .await
-            .map_err(|e| {
-                eprintln!("Failed to update task status: {}", e);
-                error::ErrorInternalServerError("Failed to update task status.")
-            })
-            .unwrap();
+                Uuid::parse_str(&task_id)?,
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            expect_affected(result, &task_id)
+        })
+    }
+
+    fn update<'a>(
+        &'a self,
+        task: Task,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let task_id = task
+                .id()
+                .ok_or_else(|| RepositoryError::NotFound("task without id".to_string()))?;
+
+            let result = sqlx::query!(
+                "UPDATE tasks SET content = $1, task_date = $2 WHERE id = $3 AND user_id = $4 AND deleted_at IS NULL",
+                task.content(),
+                chrono_to_primitive(*task.task_date()),
+                Uuid::parse_str(task_id)?,
+                Uuid::parse_str(task.user_id())?
+            )
+            .execute(db_conn)
+            .await?;
+
+            expect_affected(result, task_id)
+        })
+    }
+
+    fn soft_delete<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let result = sqlx::query!(
+                "UPDATE tasks SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL",
+                Uuid::parse_str(&task_id)?,
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            expect_affected(result, &task_id)
+        })
+    }
+
+    fn list_trash<'a>(
+        &'a self,
+        user_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Vec<Task>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+
+            let rows = sqlx::query_as!(
+                TaskRow,
+                r#"
+                SELECT id, user_id, content, task_date, created_at, deleted_at, tasks_status as "tasks_status: TaskStatus"
+                FROM tasks
+                WHERE user_id = $1 AND deleted_at IS NOT NULL
+                ORDER BY deleted_at DESC
+                "#,
+                Uuid::parse_str(&user_id)?
+            )
+            .fetch_all(db_conn)
+            .await?;
+
+            Ok(rows.into_iter().map(TaskRow::into_domain).collect())
+        })
+    }
+
+    fn restore<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let result = sqlx::query!(
+                "UPDATE tasks SET deleted_at = NULL WHERE id = $1 AND user_id = $2 AND deleted_at IS NOT NULL",
+                Uuid::parse_str(&task_id)?,
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            expect_affected(result, &task_id)
+        })
+    }
+
+    fn purge<'a>(
+        &'a self,
+        user_id: String,
+        task_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let result = sqlx::query!(
+                "DELETE FROM tasks WHERE id = $1 AND user_id = $2 AND deleted_at IS NOT NULL",
+                Uuid::parse_str(&task_id)?,
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            expect_affected(result, &task_id)
+        })
+    }
+}
+
+impl SessionRepository for SqlxRepository {
+    fn create<'a>(
+        &'a self,
+        session: Session,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            sqlx::query!(
+                "INSERT INTO sessions (id, user_id, refresh_token_hash, created_at, expires_at) VALUES ($1, $2, $3, $4, $5)",
+                Uuid::parse_str(session.id())?,
+                Uuid::parse_str(session.user_id())?,
+                session.refresh_token_hash(),
+                chrono_to_primitive(*session.created_at()),
+                chrono_to_primitive(*session.expires_at())
+            )
+            .execute(db_conn)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn find_by_refresh_token_hash<'a>(
+        &'a self,
+        refresh_token_hash: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Option<Session>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let row = sqlx::query_as!(
+                SessionRow,
+                "SELECT id, user_id, refresh_token_hash, created_at, expires_at, revoked_at FROM sessions WHERE refresh_token_hash = $1",
+                refresh_token_hash
+            )
+            .fetch_optional(db_conn)
+            .await?;
+
+            Ok(row.map(SessionRow::into_domain))
+        })
+    }
+
+    fn find_by_rotated_refresh_token_hash<'a>(
+        &'a self,
+        refresh_token_hash: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<Option<Session>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let row = sqlx::query_as!(
+                SessionRow,
+                r#"
+                SELECT s.id, s.user_id, s.refresh_token_hash, s.created_at, s.expires_at, s.revoked_at
+                FROM rotated_refresh_tokens r
+                JOIN sessions s ON s.id = r.session_id
+                WHERE r.refresh_token_hash = $1
+                "#,
+                refresh_token_hash
+            )
+            .fetch_optional(db_conn)
+            .await?;
+
+            Ok(row.map(SessionRow::into_domain))
+        })
+    }
+
+    fn rotate<'a>(
+        &'a self,
+        session_id: String,
+        current_hash: String,
+        new_hash: String,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let session_uuid = Uuid::parse_str(&session_id)?;
+            let mut transaction = self.pool.begin().await?;
+            let result = sqlx::query!(
+                "UPDATE sessions SET refresh_token_hash = $1, expires_at = $2 WHERE id = $3 AND refresh_token_hash = $4 AND revoked_at IS NULL",
+                new_hash,
+                chrono_to_primitive(expires_at),
+                session_uuid,
+                current_hash
+            )
+            .execute(&mut *transaction)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!("session {}", session_id)));
+            }
+
+            sqlx::query!(
+                "INSERT INTO rotated_refresh_tokens (refresh_token_hash, session_id, rotated_at) VALUES ($1, $2, $3)",
+                current_hash,
+                session_uuid,
+                chrono_to_primitive(chrono::Utc::now())
+            )
+            .execute(&mut *transaction)
+            .await?;
+
+            transaction.commit().await?;
+            Ok(())
+        })
+    }
+
+    fn revoke<'a>(
+        &'a self,
+        session_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            sqlx::query!(
+                "UPDATE sessions SET revoked_at = $1 WHERE id = $2 AND revoked_at IS NULL",
+                chrono_to_primitive(chrono::Utc::now()),
+                Uuid::parse_str(&session_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn revoke_all_for_user<'a>(
+        &'a self,
+        user_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            sqlx::query!(
+                "UPDATE sessions SET revoked_at = $1 WHERE user_id = $2 AND revoked_at IS NULL",
+                chrono_to_primitive(chrono::Utc::now()),
+                Uuid::parse_str(&user_id)?
+            )
+            .execute(db_conn)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn is_active<'a>(
+        &'a self,
+        session_id: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            // A malformed id cannot belong to any session.
+            let Ok(session_uuid) = Uuid::parse_str(&session_id) else {
+                return Ok(false);
+            };
+
+            let active = sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM sessions WHERE id = $1 AND revoked_at IS NULL AND expires_at > $2) as "active!""#,
+                session_uuid,
+                chrono_to_primitive(chrono::Utc::now())
+            )
+            .fetch_one(db_conn)
+            .await?;
+
+            Ok(active)
+        })
+    }
+}
+
+impl PasswordResetRepository for SqlxRepository {
+    fn create<'a>(
+        &'a self,
+        token: PasswordResetToken,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            sqlx::query!(
+                "INSERT INTO password_reset_tokens (id, user_id, token_hash, created_at, expires_at) VALUES ($1, $2, $3, $4, $5)",
+                Uuid::parse_str(token.id())?,
+                Uuid::parse_str(token.user_id())?,
+                token.token_hash(),
+                chrono_to_primitive(*token.created_at()),
+                chrono_to_primitive(*token.expires_at())
+            )
+            .execute(db_conn)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn find_by_token_hash<'a>(
+        &'a self,
+        token_hash: String,
+    ) -> std::pin::Pin<
+        Box<dyn Future<Output = Result<Option<PasswordResetToken>, RepositoryError>> + Send + 'a>,
+    > {
+        Box::pin(async move {
+            let db_conn = &self.pool;
+            let row = sqlx::query_as!(
+                PasswordResetTokenRow,
+                "SELECT id, user_id, token_hash, created_at, expires_at, used_at FROM password_reset_tokens WHERE token_hash = $1",
+                token_hash
+            )
+            .fetch_optional(db_conn)
+            .await?;
+
+            Ok(row.map(PasswordResetTokenRow::into_domain))
+        })
+    }
+
+    fn reset_password<'a>(
+        &'a self,
+        token_id: String,
+        user_id: String,
+        password_hash: String,
+    ) -> std::pin::Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let token_uuid = Uuid::parse_str(&token_id)?;
+            let user_uuid = Uuid::parse_str(&user_id)?;
+            let now = chrono_to_primitive(chrono::Utc::now());
+            let mut transaction = self.pool.begin().await?;
+
+            let result = sqlx::query!(
+                "UPDATE password_reset_tokens SET used_at = $1 WHERE id = $2 AND user_id = $3 AND used_at IS NULL",
+                now,
+                token_uuid,
+                user_uuid
+            )
+            .execute(&mut *transaction)
+            .await?;
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!(
+                    "password reset token {}",
+                    token_id
+                )));
+            }
+
+            // Links sent before this one must not reset the new password.
+            sqlx::query!(
+                "UPDATE password_reset_tokens SET used_at = $1 WHERE user_id = $2 AND used_at IS NULL",
+                now,
+                user_uuid
+            )
+            .execute(&mut *transaction)
+            .await?;
+
+            let result = sqlx::query!(
+                "UPDATE users SET password_hash = $1 WHERE id = $2",
+                password_hash,
+                user_uuid
+            )
+            .execute(&mut *transaction)
+            .await?;
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound(format!("user {}", user_id)));
+            }
+
+            sqlx::query!(
+                "UPDATE sessions SET revoked_at = $1 WHERE user_id = $2 AND revoked_at IS NULL",
+                now,
+                user_uuid
+            )
+            .execute(&mut *transaction)
+            .await?;
+
+            transaction.commit().await?;
+            Ok(())
         })
     }
 }
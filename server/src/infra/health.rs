@@ -0,0 +1,117 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use actix_web::rt::time::timeout;
+use serde::Serialize;
+use sqlx::PgPool;
+
+use crate::{
+    app::storage::object_storage::ObjectStorage, infra::db::migrations::pending_migrations,
+};
+
+/// How long each dependency may take to answer before it counts as down.
+pub const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Up,
+    Down,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DependencyCheck {
+    pub status: CheckStatus,
+    pub duration_ms: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Readiness {
+    pub status: &'static str,
+    pub checks: BTreeMap<&'static str, DependencyCheck>,
+}
+
+impl Readiness {
+    pub fn new(checks: BTreeMap<&'static str, DependencyCheck>) -> Self {
+        let is_ready = checks.values().all(|check| check.status == CheckStatus::Up);
+        Self {
+            status: if is_ready { "ready" } else { "not_ready" },
+            checks,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == "ready"
+    }
+}
+
+/// Times `call`, giving up after `limit`. Failures are logged rather than
+/// returned, so probe responses do not leak connection details.
+pub async fn check_dependency<T, E: fmt::Display>(
+    name: &'static str,
+    limit: Duration,
+    call: impl Future<Output = Result<T, E>>,
+) -> DependencyCheck {
+    let started = Instant::now();
+    let error = match timeout(limit, call).await {
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_) => Some(format!("no answer within {} ms", limit.as_millis())),
+    };
+    let duration_ms = started.elapsed().as_millis() as u64;
+    let status = match error {
+        None => CheckStatus::Up,
+        Some(error) => {
+            tracing::warn!(dependency = name, error = %error, "dependency check failed");
+            CheckStatus::Down
+        }
+    };
+    DependencyCheck {
+        status,
+        duration_ms,
+    }
+}
+
+/// Checks what the server needs to answer requests: a pooled query, an
+/// up to date schema and the object storage backend.
+pub struct ReadinessProbe {
+    pool: PgPool,
+    object_storage: Arc<dyn ObjectStorage + Send + Sync>,
+}
+
+impl ReadinessProbe {
+    pub fn new(pool: PgPool, object_storage: Arc<dyn ObjectStorage + Send + Sync>) -> Self {
+        Self {
+            pool,
+            object_storage,
+        }
+    }
+
+    pub async fn check(&self) -> Readiness {
+        let (database, migrations, storage) = futures_util::join!(
+            check_dependency(
+                "database",
+                CHECK_TIMEOUT,
+                sqlx::query("SELECT 1").execute(&self.pool)
+            ),
+            check_dependency("migrations", CHECK_TIMEOUT, async {
+                match pending_migrations(&self.pool).await {
+                    Ok(pending) if pending.is_empty() => Ok(()),
+                    Ok(pending) => Err(format!("{} migrations are pending", pending.len())),
+                    Err(e) => Err(e.to_string()),
+                }
+            }),
+            check_dependency("storage", CHECK_TIMEOUT, self.object_storage.ping()),
+        );
+        Readiness::new(BTreeMap::from([
+            ("database", database),
+            ("migrations", migrations),
+            ("storage", storage),
+        ]))
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use actix_web::rt::time::sleep;
+
+    use crate::{
+        app::storage::{mock_object_storage::MockObjectStorage, object_storage::ObjectStorage},
+        infra::health::{CheckStatus, Readiness, check_dependency},
+    };
+
+    const LIMIT: Duration = Duration::from_millis(50);
+
+    #[actix_web::test]
+    async fn test_check_dependency_should_report_answers_and_failures() {
+        // Arrange
+        let storage = MockObjectStorage::new();
+
+        // Act
+        let up = check_dependency("storage", LIMIT, storage.ping()).await;
+        storage.fail_with("unavailable");
+        let down = check_dependency("storage", LIMIT, storage.ping()).await;
+
+        // Assert
+        assert_eq!(up.status, CheckStatus::Up);
+        assert_eq!(down.status, CheckStatus::Down);
+    }
+
+    #[actix_web::test]
+    async fn test_check_dependency_without_answer_should_time_out() {
+        // Act
+        let check = check_dependency("database", LIMIT, async {
+            sleep(Duration::from_secs(5)).await;
+            Ok::<(), String>(())
+        })
+        .await;
+
+        // Assert
+        assert_eq!(check.status, CheckStatus::Down);
+        assert!(check.duration_ms < 1000);
+    }
+
+    #[actix_web::test]
+    async fn test_readiness_should_require_every_check_up() {
+        // Arrange
+        let up = || check_dependency("a", LIMIT, async { Ok::<(), String>(()) });
+        let down = || check_dependency("b", LIMIT, async { Err::<(), String>("down".into()) });
+
+        // Act
+        let ready = Readiness::new(BTreeMap::from([("a", up().await), ("b", up().await)]));
+        let not_ready = Readiness::new(BTreeMap::from([("a", up().await), ("b", down().await)]));
+
+        // Assert
+        assert!(ready.is_ready());
+        assert!(!not_ready.is_ready());
+        assert_eq!(
+            serde_json::to_value(&not_ready).unwrap()["checks"]["b"]["status"],
+            "down"
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use actix_web::{HttpResponse, put, web};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::change_password_use_case::{ChangePasswordRequest, ChangePasswordUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    current_password: String,
+    new_password: String,
+}
+
+/// Replaces the password and signs the user out of every session.
+#[put("/password")]
+pub async fn change_password_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    request_body: web::Json<Body>,
+    change_password_use_case: web::Data<ChangePasswordUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let body = request_body.into_inner();
+    let change_password_request =
+        ChangePasswordRequest::new(user.id.clone(), body.current_password, body.new_password);
+
+    change_password_use_case
+        .execute(change_password_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
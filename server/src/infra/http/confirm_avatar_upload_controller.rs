@@ -0,0 +1,41 @@
+use actix_web::{HttpResponse, put, web};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::update_avatar_use_case::{
+            AvatarChange, UpdateAvatarRequest, UpdateAvatarUseCase,
+        },
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    file_key: String,
+    mime_type: String,
+}
+
+/// Switches the avatar to a finished upload and deletes the previous picture.
+#[put("/avatar")]
+pub async fn confirm_avatar_upload_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    request_body: web::Json<Body>,
+    update_avatar_use_case: web::Data<UpdateAvatarUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let body = request_body.into_inner();
+    let update_avatar_request = UpdateAvatarRequest::new(
+        user.id.clone(),
+        AvatarChange::Uploaded {
+            file_key: body.file_key,
+            mime_type: body.mime_type,
+        },
+    );
+
+    update_avatar_use_case
+        .execute(update_avatar_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,32 @@
+use actix_web::{HttpResponse, post, web};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{
+    errors::app_error::AppError,
+    use_cases::confirm_password_reset_use_case::{
+        ConfirmPasswordResetRequest, ConfirmPasswordResetUseCase,
+    },
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    token: String,
+    new_password: String,
+}
+
+#[post("/password-reset/confirm")]
+pub async fn confirm_password_reset_controller(
+    request_body: web::Json<Body>,
+    confirm_password_reset_use_case: web::Data<ConfirmPasswordResetUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let confirm_password_reset_request = ConfirmPasswordResetRequest::new(
+        request_body.token.clone(),
+        request_body.new_password.clone(),
+    );
+
+    confirm_password_reset_use_case
+        .execute(confirm_password_reset_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
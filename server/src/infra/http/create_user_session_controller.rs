@@ -1,8 +1,14 @@
-use actix_web::{Error, HttpResponse, error, post, web};
+use actix_web::{HttpResponse, post, web};
 use serde::{Deserialize, Serialize};
 
-use crate::app::use_cases::create_user_session_use_case::{
-    CreateUserSessionRequest, CreateUserSessionUseCase,
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::create_user_session_use_case::{
+            CreateUserSessionError, CreateUserSessionRequest, CreateUserSessionUseCase,
+        },
+    },
+    infra::metrics::Metrics,
 };
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -15,18 +21,26 @@ struct Body {
 pub async fn create_user_session_controller(
     request_body: web::Json<Body>,
     create_user_session_use_case: web::Data<CreateUserSessionUseCase>,
-) -> Result<HttpResponse, Error> {
-    println!("Logging user");
-
+    metrics: web::Data<Metrics>,
+) -> Result<HttpResponse, AppError> {
     let create_user_session_request =
         CreateUserSessionRequest::new(request_body.email.clone(), request_body.password.clone());
 
-    let token = create_user_session_use_case
+    let result = create_user_session_use_case
         .execute(create_user_session_request)
         .await;
-
-    match token {
-        Ok(token) => Ok(HttpResponse::Ok().json(serde_json::json!({ "token":  token.token}))),
-        Err(e) => Err(error::ErrorInternalServerError(e.to_string())),
+    // Server side failures say nothing about the credentials.
+    match &result {
+        Ok(_) => metrics.record_login(true),
+        Err(CreateUserSessionError::InvalidCredentials(_)) => metrics.record_login(false),
+        Err(_) => {}
     }
+    let token = result?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "access_token": token.access_token,
+        "refresh_token": token.refresh_token,
+        "token_type": "Bearer",
+        "expires_in": token.expires_in,
+    })))
 }
@@ -0,0 +1,32 @@
+use actix_web::{HttpResponse, delete, web};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::delete_account_use_case::{DeleteAccountRequest, DeleteAccountUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    password: String,
+}
+
+/// Permanently deletes the account and everything it owns.
+#[delete("/account")]
+pub async fn delete_account_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    request_body: web::Json<Body>,
+    delete_account_use_case: web::Data<DeleteAccountUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let delete_account_request =
+        DeleteAccountRequest::new(user.id.clone(), request_body.password.clone());
+
+    delete_account_use_case
+        .execute(delete_account_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
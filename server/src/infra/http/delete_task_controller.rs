@@ -0,0 +1,22 @@
+use actix_web::{HttpResponse, delete, web};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::delete_task_use_case::{DeleteTaskRequest, DeleteTaskUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[delete("/{task_id}")]
+pub async fn delete_task_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    path: web::Path<String>,
+    delete_task_use_case: web::Data<DeleteTaskUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let delete_task_request = DeleteTaskRequest::new(user.id.clone(), path.into_inner());
+
+    delete_task_use_case.execute(delete_task_request).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,37 @@
+use actix_web::{HttpResponse, get, http::header, web};
+
+use crate::{
+    app::errors::app_error::AppError,
+    infra::{
+        http::upload_stored_object_controller::SignedQuery,
+        storage::local_object_storage::LocalObjectStorage,
+    },
+};
+
+/// Serves an object through a URL presigned by the local storage backend.
+#[get("/{key:.*}")]
+pub async fn download_stored_object_controller(
+    key: web::Path<String>,
+    query: web::Query<SignedQuery>,
+    local_storage: web::Data<LocalObjectStorage>,
+) -> Result<HttpResponse, AppError> {
+    local_storage.verify("GET", &key, query.expires, "", &query.signature)?;
+
+    let (bytes, content_type) = local_storage
+        .read(&key)
+        .await
+        .map_err(|e| AppError::internal(e.to_string()))?
+        .ok_or_else(|| {
+            AppError::not_found("object_not_found", "No object is stored at this key")
+        })?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        // Signed URLs are unique, so the browser may keep the bytes.
+        .insert_header((header::CACHE_CONTROL, "private, max-age=3600"))
+        // Objects are served from the API origin, so a browser must never
+        // guess another type or render one as a page there.
+        .insert_header((header::X_CONTENT_TYPE_OPTIONS, "nosniff"))
+        .insert_header((header::CONTENT_DISPOSITION, "attachment"))
+        .body(bytes))
+}
@@ -0,0 +1,58 @@
+use actix_web::{
+    HttpResponse, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web,
+};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::export_user_data_use_case::{ExportUserDataRequest, ExportUserDataUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+/// Downloads a JSON bundle with the profile, all tasks and avatar metadata.
+#[get("/export")]
+pub async fn export_user_data_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    export_user_data_use_case: web::Data<ExportUserDataUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let export_user_data_request = ExportUserDataRequest::new(user.id.clone());
+
+    let response = export_user_data_use_case
+        .execute(export_user_data_request)
+        .await?;
+
+    let avatars: Vec<serde_json::Value> = response
+        .avatars
+        .iter()
+        .map(|avatar| {
+            serde_json::json!({
+                "id": avatar.id(),
+                "file_key": avatar.file_key(),
+                "mime_type": avatar.mime_type(),
+                "thumbnail_file_key": avatar.thumbnail_file_key(),
+                "created_at": avatar.created_at(),
+            })
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok()
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(
+                "todo-tasks-export.json".to_string(),
+            )],
+        })
+        .json(serde_json::json!({
+            "exported_at": response.exported_at,
+            "profile": {
+                "id": response.user.id(),
+                "username": response.user.username(),
+                "email": response.user.email(),
+            },
+            "tasks": response.tasks,
+            "avatars": avatars,
+        })))
+}
@@ -0,0 +1,22 @@
+use actix_web::{HttpResponse, get, web};
+
+use crate::infra::health::ReadinessProbe;
+
+/// Answers as long as the process serves requests; dependencies are not
+/// checked, so an outage does not get the server restarted.
+#[get("/health/live")]
+pub async fn liveness_controller() -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({ "status": "ok" }))
+}
+
+/// `200` when every dependency is up, `503` otherwise, with the status and
+/// timing of each check.
+#[get("/health/ready")]
+pub async fn readiness_controller(readiness_probe: web::Data<ReadinessProbe>) -> HttpResponse {
+    let readiness = readiness_probe.check().await;
+    if readiness.is_ready() {
+        HttpResponse::Ok().json(readiness)
+    } else {
+        HttpResponse::ServiceUnavailable().json(readiness)
+    }
+}
@@ -1,21 +1,51 @@
-use actix_web::{Error, HttpResponse, get, web};
+use actix_web::{HttpResponse, get, web};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
 
 use crate::{
-    app::use_cases::list_all_tasks_use_case::{ListAllTasksRequest, ListAllTasksUseCase},
+    app::{
+        entities::task_status::TaskStatus,
+        errors::app_error::AppError,
+        repositories::task_repository::{SortDirection, TaskSortField},
+        use_cases::list_all_tasks_use_case::{ListAllTasksRequest, ListAllTasksUseCase},
+    },
     infra::middlewares::check_request_jwt::AuthenticatedUser,
 };
 
+#[derive(Deserialize, Debug)]
+struct Params {
+    status: Option<TaskStatus>,
+    task_date_from: Option<DateTime<Utc>>,
+    task_date_to: Option<DateTime<Utc>>,
+    sort_by: Option<TaskSortField>,
+    direction: Option<SortDirection>,
+    cursor: Option<String>,
+    limit: Option<i64>,
+}
+
 #[get("/list")]
 pub async fn list_all_user_tasks_controller(
     user: web::ReqData<AuthenticatedUser>,
+    params: web::Query<Params>,
     list_all_tasks_use_case: web::Data<ListAllTasksUseCase>,
-) -> Result<HttpResponse, Error> {
-    println!("List controller: =====================================");
-    let list_all_user_tasks_request = ListAllTasksRequest::new(user.id.clone());
+) -> Result<HttpResponse, AppError> {
+    let params = params.into_inner();
+    let list_all_user_tasks_request = ListAllTasksRequest {
+        status: params.status,
+        task_date_from: params.task_date_from,
+        task_date_to: params.task_date_to,
+        sort_by: params.sort_by.unwrap_or_default(),
+        direction: params.direction.unwrap_or_default(),
+        cursor: params.cursor,
+        limit: params.limit,
+        ..ListAllTasksRequest::new(user.id.clone())
+    };
 
     let response = list_all_tasks_use_case
         .execute(list_all_user_tasks_request)
-        .await;
+        .await?;
 
-    Ok(HttpResponse::Ok().json(serde_json::json!({ "tasks":  response.tasks  })))
+    Ok(HttpResponse::Ok().json(
+        serde_json::json!({ "tasks":  response.tasks, "next_cursor": response.next_cursor  }),
+    ))
 }
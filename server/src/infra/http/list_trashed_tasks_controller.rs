@@ -0,0 +1,25 @@
+use actix_web::{HttpResponse, get, web};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::list_trashed_tasks_use_case::{
+            ListTrashedTasksRequest, ListTrashedTasksUseCase,
+        },
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[get("/trash")]
+pub async fn list_trashed_tasks_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    list_trashed_tasks_use_case: web::Data<ListTrashedTasksUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let list_trashed_tasks_request = ListTrashedTasksRequest::new(user.id.clone());
+
+    let response = list_trashed_tasks_use_case
+        .execute(list_trashed_tasks_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "tasks":  response.tasks  })))
+}
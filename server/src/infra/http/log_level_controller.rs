@@ -0,0 +1,39 @@
+use actix_web::{HttpResponse, get, put, web};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::errors::app_error::{AppError, InvalidParam},
+    infra::logging::LogLevelHandle,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    level: String,
+}
+
+#[get("/log-level")]
+pub async fn get_log_level_controller(
+    log_level: web::Data<LogLevelHandle>,
+) -> Result<HttpResponse, AppError> {
+    Ok(HttpResponse::Ok().json(Body {
+        level: log_level.current(),
+    }))
+}
+
+/// Swaps the log filter without a restart, e.g. `{"level": "debug"}` or
+/// `{"level": "info,server=debug"}`.
+#[put("/log-level")]
+pub async fn set_log_level_controller(
+    request_body: web::Json<Body>,
+    log_level: web::Data<LogLevelHandle>,
+) -> Result<HttpResponse, AppError> {
+    log_level.set(&request_body.level).map_err(|e| {
+        AppError::unprocessable("validation_failed", "One or more fields are invalid")
+            .with_invalid_params(vec![InvalidParam::new("level", e.to_string())])
+    })?;
+    tracing::info!(level = %request_body.level, "log level changed");
+
+    Ok(HttpResponse::Ok().json(Body {
+        level: log_level.current(),
+    }))
+}
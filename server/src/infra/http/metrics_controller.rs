@@ -0,0 +1,22 @@
+use actix_web::{HttpResponse, get, web};
+
+use crate::{infra::metrics::Metrics, startup::AppState};
+
+/// Prometheus scrape endpoint, mounted under `/admin` behind the admin token.
+/// Pool gauges are read at scrape time.
+#[get("/metrics")]
+pub async fn metrics_controller(
+    metrics: web::Data<Metrics>,
+    app_state: web::Data<AppState>,
+) -> HttpResponse {
+    let pool = &app_state.conn;
+    metrics.set_db_pool(
+        pool.size(),
+        pool.num_idle(),
+        pool.options().get_max_connections(),
+    );
+
+    HttpResponse::Ok()
+        .content_type(prometheus::TEXT_FORMAT)
+        .body(metrics.render())
+}
@@ -1,6 +1,29 @@
+pub mod change_password_controller;
+pub mod confirm_avatar_upload_controller;
+pub mod confirm_password_reset_controller;
 pub mod create_user_session_controller;
+pub mod delete_account_controller;
+pub mod delete_task_controller;
+pub mod download_stored_object_controller;
+pub mod export_user_data_controller;
 pub mod get_user_profile_controller;
+pub mod health_controller;
 pub mod list_all_user_tasks_controller;
+pub mod list_trashed_tasks_controller;
+pub mod log_level_controller;
+pub mod metrics_controller;
+pub mod problem_details;
+pub mod purge_task_controller;
+pub mod refresh_session_controller;
 pub mod register_user_controller;
+pub mod request_avatar_upload_controller;
+pub mod request_password_reset_controller;
+pub mod reset_avatar_controller;
+pub mod restore_task_controller;
+pub mod revoke_all_sessions_controller;
+pub mod revoke_session_controller;
 pub mod save_task_controller;
+pub mod update_task_controller;
 pub mod update_task_status_controller;
+pub mod update_user_profile_controller;
+pub mod upload_stored_object_controller;
@@ -0,0 +1,88 @@
+use actix_web::{
+    HttpRequest, HttpResponse, ResponseError,
+    error::{JsonPayloadError, PathError, QueryPayloadError},
+    http::StatusCode,
+};
+use serde::Serialize;
+
+use crate::app::errors::app_error::{AppError, ErrorKind};
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// RFC 7807 problem details body. `code` is an extension member holding the
+/// stable identifier clients should branch on instead of parsing `detail`.
+#[derive(Serialize)]
+struct ProblemDetails<'a> {
+    #[serde(rename = "type")]
+    problem_type: &'a str,
+    title: &'a str,
+    status: u16,
+    detail: &'a str,
+    code: &'a str,
+    #[serde(rename = "invalid-params", skip_serializing_if = "Vec::is_empty")]
+    invalid_params: Vec<InvalidParamBody<'a>>,
+}
+
+#[derive(Serialize)]
+struct InvalidParamBody<'a> {
+    name: &'a str,
+    reason: &'a str,
+}
+
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self.kind() {
+            ErrorKind::BadRequest => StatusCode::BAD_REQUEST,
+            ErrorKind::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorKind::Forbidden => StatusCode::FORBIDDEN,
+            ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            ErrorKind::Conflict => StatusCode::CONFLICT,
+            ErrorKind::UnprocessableEntity => StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorKind::BadGateway => StatusCode::BAD_GATEWAY,
+            ErrorKind::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorKind::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let status = self.status_code();
+
+        // Server side details stay in the logs; clients get a generic detail.
+        let detail = if status.is_server_error() {
+            tracing::error!(code = self.code(), error = %self, "request failed");
+            "The server failed to process the request"
+        } else {
+            self.detail()
+        };
+
+        HttpResponse::build(status)
+            .content_type(PROBLEM_JSON)
+            .json(ProblemDetails {
+                problem_type: "about:blank",
+                title: status.canonical_reason().unwrap_or("Error"),
+                status: status.as_u16(),
+                detail,
+                code: self.code(),
+                invalid_params: self
+                    .invalid_params()
+                    .iter()
+                    .map(|param| InvalidParamBody {
+                        name: param.name,
+                        reason: &param.reason,
+                    })
+                    .collect(),
+            })
+    }
+}
+
+pub fn json_error_handler(error: JsonPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    AppError::bad_request("invalid_body", error.to_string()).into()
+}
+
+pub fn query_error_handler(error: QueryPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    AppError::bad_request("invalid_query", error.to_string()).into()
+}
+
+pub fn path_error_handler(error: PathError, _req: &HttpRequest) -> actix_web::Error {
+    AppError::bad_request("invalid_path", error.to_string()).into()
+}
@@ -0,0 +1,22 @@
+use actix_web::{HttpResponse, delete, web};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::purge_task_use_case::{PurgeTaskRequest, PurgeTaskUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[delete("/trash/{task_id}")]
+pub async fn purge_task_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    path: web::Path<String>,
+    purge_task_use_case: web::Data<PurgeTaskUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let purge_task_request = PurgeTaskRequest::new(user.id.clone(), path.into_inner());
+
+    purge_task_use_case.execute(purge_task_request).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
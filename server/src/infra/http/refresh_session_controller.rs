@@ -0,0 +1,31 @@
+use actix_web::{HttpResponse, post, web};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{
+    errors::app_error::AppError,
+    use_cases::refresh_session_use_case::{RefreshSessionRequest, RefreshSessionUseCase},
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    refresh_token: String,
+}
+
+#[post("/sessions/refresh")]
+pub async fn refresh_session_controller(
+    request_body: web::Json<Body>,
+    refresh_session_use_case: web::Data<RefreshSessionUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let refresh_session_request = RefreshSessionRequest::new(request_body.refresh_token.clone());
+
+    let tokens = refresh_session_use_case
+        .execute(refresh_session_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "access_token": tokens.access_token,
+        "refresh_token": tokens.refresh_token,
+        "token_type": "Bearer",
+        "expires_in": tokens.expires_in,
+    })))
+}
@@ -1,12 +1,9 @@
-use actix_web::{Error, HttpResponse, error, post, web};
+use actix_web::{HttpResponse, post, web};
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    app::use_cases::{
-        get_signed_url_use_case::{self, GetSignedUrlUseCase},
-        register_user_use_case::{self, RegisterUserUseCase},
-    },
-    env::get_env_var,
+use crate::app::{
+    errors::app_error::AppError,
+    use_cases::register_user_use_case::{RegisterUserRequest, RegisterUserUseCase},
 };
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -14,7 +11,7 @@ struct Body {
     email: String,
     username: String,
     password: String,
-    file_key: Option<String>,
+    /// Set when the client wants to upload a picture of this type.
     mime_type: Option<String>,
 }
 
@@ -22,43 +19,22 @@ struct Body {
 pub async fn register_user_controller(
     request_body: web::Json<Body>,
     register_user_use_case: web::Data<RegisterUserUseCase>,
-    get_signed_url_use_case: web::Data<GetSignedUrlUseCase>,
-) -> Result<HttpResponse, Error> {
-    println!("Registering user");
-
-    let mut file_key = request_body
-        .file_key
-        .clone()
-        .unwrap_or_else(|| get_env_var("DEFAULT_AVATAR").expect("DEFAULT_AVATAR must be set"));
-
-    let mime_type = request_body
-        .mime_type
-        .clone()
-        .unwrap_or_else(|| String::from("image/jpeg"));
-
-    file_key = format!("{file_key}-{}", uuid::Uuid::new_v4());
-
-    let register_user_request = register_user_use_case::RegisterUserRequest::new(
-        request_body.email.to_owned(),
-        request_body.username.to_owned(),
-        request_body.password.to_owned(),
-        file_key.clone(),
-        mime_type.clone(),
-    );
-
-    let get_signed_url_request = get_signed_url_use_case::GetSignedUrlUseCaseRequest::new(
-        file_key.clone(),
-        mime_type.clone(),
+) -> Result<HttpResponse, AppError> {
+    let request_body = request_body.into_inner();
+    let register_user_request = RegisterUserRequest::new(
+        request_body.email,
+        request_body.username,
+        request_body.password,
+        request_body.mime_type,
     );
 
-    register_user_use_case.execute(register_user_request).await;
-    let response = get_signed_url_use_case
-        .execute(get_signed_url_request)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to get signed URL: {}", e);
-            error::ErrorInternalServerError("Failed to process request")
-        })?;
+    let response = register_user_use_case
+        .execute(register_user_request)
+        .await?;
 
-    Ok(HttpResponse::Ok().json(serde_json::json!({ "url":  response.url})))
+    let avatar_upload = response
+        .avatar_upload
+        .map(|upload| serde_json::json!({ "url": upload.url, "file_key": upload.file_key }));
+    Ok(HttpResponse::Created()
+        .json(serde_json::json!({ "id": response.user_id, "avatar_upload": avatar_upload })))
 }
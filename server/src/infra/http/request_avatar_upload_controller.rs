@@ -0,0 +1,44 @@
+use actix_web::{HttpResponse, post, web};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::{
+            get_signed_url_use_case::{GetSignedUrlUseCase, GetSignedUrlUseCaseRequest},
+            update_avatar_use_case::{new_avatar_file_key, validate_avatar_mime_type},
+        },
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    mime_type: String,
+}
+
+/// Hands out an upload URL for a new avatar. The avatar only changes once
+/// the upload is confirmed with `PUT /users/avatar`.
+#[post("/avatar/upload-url")]
+pub async fn request_avatar_upload_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    request_body: web::Json<Body>,
+    get_signed_url_use_case: web::Data<GetSignedUrlUseCase>,
+) -> Result<HttpResponse, AppError> {
+    if let Some(invalid_param) = validate_avatar_mime_type(&request_body.mime_type) {
+        return Err(
+            AppError::unprocessable("validation_failed", "One or more fields are invalid")
+                .with_invalid_params(vec![invalid_param]),
+        );
+    }
+
+    let file_key = new_avatar_file_key(&user.id);
+    let get_signed_url_request =
+        GetSignedUrlUseCaseRequest::new(file_key.clone(), request_body.mime_type.clone());
+
+    let response = get_signed_url_use_case
+        .execute(get_signed_url_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "url": response.url, "file_key": file_key })))
+}
@@ -0,0 +1,38 @@
+use actix_web::{HttpResponse, post, web};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{
+    errors::app_error::AppError,
+    use_cases::request_password_reset_use_case::{
+        RequestPasswordResetRequest, RequestPasswordResetUseCase,
+    },
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    email: String,
+}
+
+#[post("/password-reset")]
+pub async fn request_password_reset_controller(
+    request_body: web::Json<Body>,
+    request_password_reset_use_case: web::Data<RequestPasswordResetUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let request_password_reset_request =
+        RequestPasswordResetRequest::new(request_body.email.clone());
+
+    // Looking the account up, storing a token and notifying only happen
+    // for known emails; doing them after answering keeps the response time
+    // the same either way. Failures can only be logged.
+    let request_password_reset_use_case = request_password_reset_use_case.into_inner();
+    actix_web::rt::spawn(async move {
+        if let Err(e) = request_password_reset_use_case
+            .execute(request_password_reset_request)
+            .await
+        {
+            tracing::error!(error = %e, "failed to handle password reset request");
+        }
+    });
+
+    Ok(HttpResponse::Accepted().finish())
+}
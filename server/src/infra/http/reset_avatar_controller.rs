@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, delete, web};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::update_avatar_use_case::{
+            AvatarChange, UpdateAvatarRequest, UpdateAvatarUseCase,
+        },
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+/// Goes back to the default avatar and deletes the uploaded picture.
+#[delete("/avatar")]
+pub async fn reset_avatar_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    update_avatar_use_case: web::Data<UpdateAvatarUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let update_avatar_request = UpdateAvatarRequest::new(user.id.clone(), AvatarChange::Default);
+
+    update_avatar_use_case
+        .execute(update_avatar_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,22 @@
+use actix_web::{HttpResponse, put, web};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::restore_task_use_case::{RestoreTaskRequest, RestoreTaskUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[put("/trash/{task_id}/restore")]
+pub async fn restore_task_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    path: web::Path<String>,
+    restore_task_use_case: web::Data<RestoreTaskUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let restore_task_request = RestoreTaskRequest::new(user.id.clone(), path.into_inner());
+
+    restore_task_use_case.execute(restore_task_request).await?;
+
+    Ok(HttpResponse::Ok().into())
+}
@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, delete, web};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::revoke_all_sessions_use_case::{
+            RevokeAllSessionsRequest, RevokeAllSessionsUseCase,
+        },
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+/// Logs the user out everywhere by revoking every session they own.
+#[delete("/sessions")]
+pub async fn revoke_all_sessions_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    revoke_all_sessions_use_case: web::Data<RevokeAllSessionsUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let revoke_all_sessions_request = RevokeAllSessionsRequest::new(user.id.clone());
+
+    revoke_all_sessions_use_case
+        .execute(revoke_all_sessions_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,26 @@
+use actix_web::{HttpResponse, post, web};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{
+    errors::app_error::AppError,
+    use_cases::revoke_session_use_case::{RevokeSessionRequest, RevokeSessionUseCase},
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    refresh_token: String,
+}
+
+#[post("/sessions/logout")]
+pub async fn revoke_session_controller(
+    request_body: web::Json<Body>,
+    revoke_session_use_case: web::Data<RevokeSessionUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let revoke_session_request = RevokeSessionRequest::new(request_body.refresh_token.clone());
+
+    revoke_session_use_case
+        .execute(revoke_session_request)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
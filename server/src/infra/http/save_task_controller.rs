@@ -1,10 +1,13 @@
-use actix_web::{Error, HttpResponse, post, web};
-use chrono::{DateTime, Local, Utc};
+use actix_web::{HttpResponse, post, web};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    app::use_cases::save_task_use_case::{SaveTaskRequest, SaveTaskUseCase},
-    infra::middlewares::check_request_jwt::AuthenticatedUser,
+    app::{
+        errors::app_error::AppError,
+        use_cases::save_task_use_case::{SaveTaskRequest, SaveTaskUseCase},
+    },
+    infra::{metrics::Metrics, middlewares::check_request_jwt::AuthenticatedUser},
 };
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -18,18 +21,20 @@ pub async fn save_task_controller(
     user: web::ReqData<AuthenticatedUser>,
     request_body: web::Json<Body>,
     save_task_use_case: web::Data<SaveTaskUseCase>,
-) -> Result<HttpResponse, Error> {
+    metrics: web::Data<Metrics>,
+) -> Result<HttpResponse, AppError> {
     let task_date: DateTime<Utc> = request_body
         .task_date
         .parse()
-        .expect("Failed to parse date");
-
-    println!("{:?}", task_date);
+        .map_err(|_| AppError::bad_request("invalid_task_date", "Invalid task_date"))?;
 
     let save_task_use_case_request =
         SaveTaskRequest::new(user.id.clone(), request_body.content.to_owned(), task_date);
 
-    save_task_use_case.execute(save_task_use_case_request).await;
+    save_task_use_case
+        .execute(save_task_use_case_request)
+        .await?;
+    metrics.record_task_created();
 
     Ok(HttpResponse::Ok().into())
 }
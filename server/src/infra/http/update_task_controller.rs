@@ -0,0 +1,45 @@
+use actix_web::{HttpResponse, route, web};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::update_task_use_case::{UpdateTaskRequest, UpdateTaskUseCase},
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    content: Option<String>,
+    task_date: Option<String>,
+}
+
+#[route("/{task_id}", method = "PUT", method = "PATCH")]
+pub async fn update_task_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    path: web::Path<String>,
+    request_body: web::Json<Body>,
+    update_task_use_case: web::Data<UpdateTaskUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let task_date = match &request_body.task_date {
+        Some(task_date) => Some(
+            task_date
+                .parse::<DateTime<Utc>>()
+                .map_err(|_| AppError::bad_request("invalid_task_date", "Invalid task_date"))?,
+        ),
+        None => None,
+    };
+
+    let update_task_request = UpdateTaskRequest::new(
+        user.id.clone(),
+        path.into_inner(),
+        request_body.content.clone(),
+        task_date,
+    );
+
+    update_task_use_case.execute(update_task_request).await?;
+
+    Ok(HttpResponse::Ok().into())
+}
@@ -0,0 +1,45 @@
+use actix_web::{HttpResponse, patch, web};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        errors::app_error::AppError,
+        use_cases::update_user_profile_use_case::{
+            UpdateUserProfileRequest, UpdateUserProfileUseCase,
+        },
+    },
+    infra::middlewares::check_request_jwt::AuthenticatedUser,
+};
+
+#[derive(Deserialize, Serialize, Debug)]
+struct Body {
+    username: Option<String>,
+    email: Option<String>,
+    current_password: Option<String>,
+}
+
+/// Changes the username and/or email. A new email also needs the current
+/// password.
+#[patch("/profile")]
+pub async fn update_user_profile_controller(
+    user: web::ReqData<AuthenticatedUser>,
+    request_body: web::Json<Body>,
+    update_user_profile_use_case: web::Data<UpdateUserProfileUseCase>,
+) -> Result<HttpResponse, AppError> {
+    let body = request_body.into_inner();
+    let update_user_profile_request = UpdateUserProfileRequest::new(
+        user.id.clone(),
+        body.username,
+        body.email,
+        body.current_password,
+    );
+
+    let response = update_user_profile_use_case
+        .execute(update_user_profile_request)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "username": response.user.username(),
+        "email": response.user.email(),
+    })))
+}
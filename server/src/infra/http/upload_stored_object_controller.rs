@@ -0,0 +1,47 @@
+use actix_web::{HttpRequest, HttpResponse, http::header, put, web};
+use serde::Deserialize;
+
+use crate::{
+    app::errors::app_error::AppError,
+    infra::storage::local_object_storage::{LocalObjectStorage, SignatureError},
+};
+
+#[derive(Deserialize, Debug)]
+pub struct SignedQuery {
+    pub expires: i64,
+    pub signature: String,
+}
+
+impl From<SignatureError> for AppError {
+    fn from(error: SignatureError) -> Self {
+        match error {
+            SignatureError::Invalid => AppError::forbidden("invalid_signature", error.to_string()),
+            SignatureError::Expired => AppError::forbidden("signature_expired", error.to_string()),
+        }
+    }
+}
+
+/// Receives an upload sent to a URL presigned by the local storage backend.
+#[put("/{key:.*}")]
+pub async fn upload_stored_object_controller(
+    req: HttpRequest,
+    key: web::Path<String>,
+    query: web::Query<SignedQuery>,
+    body: web::Bytes,
+    local_storage: web::Data<LocalObjectStorage>,
+) -> Result<HttpResponse, AppError> {
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .trim();
+
+    local_storage.verify("PUT", &key, query.expires, content_type, &query.signature)?;
+    local_storage
+        .write(&key, content_type, body)
+        .await
+        .map_err(|e| AppError::internal(e.to_string()))?;
+
+    Ok(HttpResponse::Ok().finish())
+}
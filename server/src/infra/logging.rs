@@ -0,0 +1,87 @@
+use std::fmt;
+
+use tracing_subscriber::{
+    EnvFilter, Registry, fmt as format, layer::SubscriberExt, reload, util::SubscriberInitExt,
+};
+
+use crate::settings::{LogFormat, LogSettings};
+
+/// Query parameters whose values never reach the logs: URL signatures and
+/// anything that grants access on its own.
+const SENSITIVE_QUERY_PARAMS: &[&str] = &[
+    "signature",
+    "token",
+    "access_token",
+    "refresh_token",
+    "password",
+    "x-amz-signature",
+    "x-amz-credential",
+    "x-amz-security-token",
+];
+const REDACTED: &str = "[REDACTED]";
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidLogLevel(pub String);
+
+impl fmt::Display for InvalidLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid log level: {}", self.0)
+    }
+}
+
+/// Changes the level of the installed subscriber while the server runs.
+#[derive(Clone)]
+pub struct LogLevelHandle {
+    handle: reload::Handle<EnvFilter, Registry>,
+}
+
+impl LogLevelHandle {
+    /// The active filter directives.
+    pub fn current(&self) -> String {
+        self.handle
+            .with_current(|filter| filter.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, directives: &str) -> Result<(), InvalidLogLevel> {
+        let filter = EnvFilter::try_new(directives).map_err(|e| InvalidLogLevel(e.to_string()))?;
+        self.handle
+            .reload(filter)
+            .map_err(|e| InvalidLogLevel(e.to_string()))
+    }
+}
+
+/// Installs the global subscriber. Records of the `log` crate (actix, sqlx)
+/// are forwarded to it as well.
+pub fn init_logging(settings: &LogSettings) -> LogLevelHandle {
+    let filter = EnvFilter::try_new(&settings.level).expect("the level is validated by Settings");
+    let (filter, handle) = reload::Layer::new(filter);
+    let registry = tracing_subscriber::registry().with(filter);
+    match settings.format {
+        LogFormat::Json => registry
+            .with(
+                format::layer()
+                    .json()
+                    .flatten_event(true)
+                    .with_current_span(true)
+                    .with_span_list(false),
+            )
+            .init(),
+        LogFormat::Pretty => registry.with(format::layer()).init(),
+    }
+    LogLevelHandle { handle }
+}
+
+/// Replaces the values of sensitive parameters in a query string.
+pub fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if SENSITIVE_QUERY_PARAMS.contains(&key.to_lowercase().as_str()) => {
+                format!("{key}={REDACTED}")
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
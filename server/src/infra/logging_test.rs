@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::infra::logging::redact_query;
+
+    #[test]
+    fn test_redact_query_should_hide_sensitive_values() {
+        assert_eq!(
+            redact_query("expires=1700000000&signature=abc123"),
+            "expires=1700000000&signature=[REDACTED]"
+        );
+        assert_eq!(
+            redact_query("X-Amz-Credential=key%2F2024&X-Amz-Signature=def&X-Amz-Expires=600"),
+            "X-Amz-Credential=[REDACTED]&X-Amz-Signature=[REDACTED]&X-Amz-Expires=600"
+        );
+        assert_eq!(
+            redact_query("token=t&cursor=c"),
+            "token=[REDACTED]&cursor=c"
+        );
+    }
+
+    #[test]
+    fn test_redact_query_should_keep_other_parameters() {
+        assert_eq!(redact_query("limit=20&flag"), "limit=20&flag");
+    }
+}
@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+
+use crate::app::entities::task_status::TaskStatus;
+
+/// Every metric the server exposes on `/admin/metrics`, in a registry of its own.
+/// Created once at startup and shared with the middlewares, controllers and
+/// object storage that record into it.
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    db_pool_max_connections: IntGauge,
+    object_storage_duration: HistogramVec,
+    object_storage_failures: IntCounterVec,
+    logins: IntCounterVec,
+    tasks_created: IntCounter,
+    task_status_transitions: IntCounterVec,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests answered"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to answer HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new(
+                "db_pool_connections",
+                "Open database connections by state (idle or in_use)",
+            ),
+            &["state"],
+        )
+        .unwrap();
+        let db_pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Maximum number of database connections",
+        )
+        .unwrap();
+        let object_storage_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "object_storage_operation_duration_seconds",
+                "Time taken by object storage operations, retries included",
+            ),
+            &["operation", "outcome"],
+        )
+        .unwrap();
+        let object_storage_failures = IntCounterVec::new(
+            Opts::new(
+                "object_storage_operation_failures_total",
+                "Object storage operations that failed",
+            ),
+            &["operation"],
+        )
+        .unwrap();
+        let logins = IntCounterVec::new(
+            Opts::new(
+                "logins_total",
+                "Login attempts by outcome (success or failure)",
+            ),
+            &["outcome"],
+        )
+        .unwrap();
+        let tasks_created = IntCounter::new("tasks_created_total", "Tasks created").unwrap();
+        let task_status_transitions = IntCounterVec::new(
+            Opts::new(
+                "task_status_transitions_total",
+                "Task status changes by previous and new status",
+            ),
+            &["from", "to"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(db_pool_connections.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(db_pool_max_connections.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(object_storage_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(object_storage_failures.clone()))
+            .unwrap();
+        registry.register(Box::new(logins.clone())).unwrap();
+        registry.register(Box::new(tasks_created.clone())).unwrap();
+        registry
+            .register(Box::new(task_status_transitions.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_pool_connections,
+            db_pool_max_connections,
+            object_storage_duration,
+            object_storage_failures,
+            logins,
+            tasks_created,
+            task_status_transitions,
+        }
+    }
+
+    /// `route` is the matched pattern rather than the path, so ids do not
+    /// create a series each.
+    pub fn observe_http_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn set_db_pool(&self, size: u32, idle: usize, max_connections: u32) {
+        let idle = idle as i64;
+        self.db_pool_connections
+            .with_label_values(&["idle"])
+            .set(idle);
+        self.db_pool_connections
+            .with_label_values(&["in_use"])
+            .set(size as i64 - idle);
+        self.db_pool_max_connections.set(max_connections as i64);
+    }
+
+    pub fn observe_object_storage(&self, operation: &str, succeeded: bool, elapsed: Duration) {
+        let outcome = if succeeded { "success" } else { "failure" };
+        self.object_storage_duration
+            .with_label_values(&[operation, outcome])
+            .observe(elapsed.as_secs_f64());
+        if !succeeded {
+            self.object_storage_failures
+                .with_label_values(&[operation])
+                .inc();
+        }
+    }
+
+    pub fn record_login(&self, succeeded: bool) {
+        let outcome = if succeeded { "success" } else { "failure" };
+        self.logins.with_label_values(&[outcome]).inc();
+    }
+
+    pub fn record_task_created(&self) {
+        self.tasks_created.inc();
+    }
+
+    pub fn record_task_status_transition(&self, from: TaskStatus, to: TaskStatus) {
+        self.task_status_transitions
+            .with_label_values(&[status_label(from), status_label(to)])
+            .inc();
+    }
+
+    /// The registry in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("Failed to encode metrics");
+        String::from_utf8(buffer).expect("Metrics are valid UTF-8")
+    }
+}
+
+fn status_label(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::ToStart => "to_start",
+        TaskStatus::Started => "started",
+        TaskStatus::Completed => "completed",
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{app::entities::task_status::TaskStatus, infra::metrics::Metrics};
+
+    #[test]
+    fn test_render_should_expose_recorded_values() {
+        // Arrange
+        let metrics = Metrics::new();
+
+        // Act
+        metrics.observe_http_request("GET", "/tasks/list", 200, Duration::from_millis(12));
+        metrics.observe_http_request("GET", "/tasks/list", 200, Duration::from_millis(30));
+        metrics.set_db_pool(4, 1, 10);
+        metrics.record_login(true);
+        metrics.record_login(false);
+        metrics.record_task_created();
+        metrics.record_task_status_transition(TaskStatus::ToStart, TaskStatus::Started);
+        let rendered = metrics.render();
+
+        // Assert
+        for line in [
+            r#"http_requests_total{method="GET",route="/tasks/list",status="200"} 2"#,
+            r#"http_request_duration_seconds_count{method="GET",route="/tasks/list",status="200"} 2"#,
+            r#"db_pool_connections{state="idle"} 1"#,
+            r#"db_pool_connections{state="in_use"} 3"#,
+            "db_pool_max_connections 10",
+            r#"logins_total{outcome="success"} 1"#,
+            r#"logins_total{outcome="failure"} 1"#,
+            "tasks_created_total 1",
+            r#"task_status_transitions_total{from="to_start",to="started"} 1"#,
+        ] {
+            assert!(rendered.lines().any(|l| l == line), "missing `{}`", line);
+        }
+    }
+}
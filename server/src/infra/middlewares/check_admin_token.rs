@@ -0,0 +1,51 @@
+use actix_web::{
+    Error,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header,
+    middleware::Next,
+    web,
+};
+use sha2::{Digest, Sha256};
+
+use crate::app::errors::app_error::AppError;
+
+/// Token guarding the operational `/admin` endpoints.
+pub struct AdminToken(String);
+
+impl AdminToken {
+    pub fn new(token: String) -> Self {
+        Self(token)
+    }
+
+    /// Compares digests so the time taken does not depend on how much of the
+    /// candidate matches.
+    fn matches(&self, candidate: &str) -> bool {
+        Sha256::digest(self.0.as_bytes()) == Sha256::digest(candidate.as_bytes())
+    }
+}
+
+pub async fn check_admin_token(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let admin_token = req
+        .app_data::<web::Data<AdminToken>>()
+        .cloned()
+        .ok_or_else(|| AppError::internal("Admin token is not configured"))?;
+    let is_authorized = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| admin_token.matches(token));
+    if !is_authorized {
+        return Err(AppError::unauthorized(
+            "invalid_admin_token",
+            "A valid admin token is required",
+        )
+        .into());
+    }
+
+    next.call(req).await
+}
@@ -1,90 +1,132 @@
 use std::collections::HashSet;
 
 use actix_web::{
-    Error, HttpMessage,
+    Error, HttpMessage, ResponseError,
     body::MessageBody,
     dev::{ServiceRequest, ServiceResponse},
-    error,
-    http::header,
+    error::InternalError,
+    http::header::{self, HeaderValue},
     middleware::Next,
+    web,
 };
-use jsonwebtoken::{DecodingKey, Header, decode};
-use serde::{Deserialize, Serialize};
+use jsonwebtoken::{DecodingKey, decode, errors::ErrorKind};
 
-#[derive(Debug, Deserialize, Serialize)]
-struct Claims {
-    sub: String,
-    exp: usize,
-}
+use crate::{
+    app::{
+        errors::app_error::AppError, repositories::session_repository::SessionRepository,
+        use_cases::session_tokens::AccessTokenClaims,
+    },
+    settings::JwtSettings,
+};
 
-#[derive(Debug, Deserialize, Serialize)]
-struct Token {
-    claims: Claims,
-    header: Header,
+/// A struct to hold the authenticated user's ID and the session the access
+/// token was issued for.
+#[derive(Clone)]
+pub struct AuthenticatedUser {
+    pub id: String,
+    pub session_id: String,
 }
 
-impl Token {
-    pub fn new(claims: Claims, header: Header) -> Self {
-        Self { claims, header }
+const REALM: &str = "todo-tasks";
+
+/// Builds a 401 problem response carrying the RFC 6750 `WWW-Authenticate`
+/// challenge. `bearer_error` is omitted when no credentials were sent at all.
+fn unauthorized(code: &'static str, detail: &str, bearer_error: Option<&str>) -> Error {
+    let error = AppError::unauthorized(code, detail);
+    let challenge = match bearer_error {
+        Some(bearer_error) => format!(
+            r#"Bearer realm="{REALM}", error="{bearer_error}", error_description="{detail}""#
+        ),
+        None => format!(r#"Bearer realm="{REALM}""#),
+    };
+
+    let mut response = error.error_response();
+    if let Ok(value) = HeaderValue::from_str(&challenge) {
+        response
+            .headers_mut()
+            .insert(header::WWW_AUTHENTICATE, value);
     }
+
+    InternalError::from_response(error, response).into()
 }
 
-/// A struct to hold the authenticated user's ID.
-#[derive(Clone)]
-pub struct AuthenticatedUser {
-    pub id: String,
+fn bearer_token(req: &ServiceRequest) -> Result<&str, Error> {
+    let header = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .ok_or_else(|| unauthorized("missing_token", "Authorization header is required", None))?;
+
+    let value = header.to_str().unwrap_or_default();
+    match value.split_once(' ') {
+        Some((scheme, token)) if scheme.eq_ignore_ascii_case("Bearer") && !token.is_empty() => {
+            Ok(token.trim())
+        }
+        _ => Err(unauthorized(
+            "invalid_token",
+            "Authorization header must use the Bearer scheme",
+            Some("invalid_request"),
+        )),
+    }
 }
 
 pub async fn check_request_jwt(
     req: ServiceRequest,
     next: Next<impl MessageBody>,
 ) -> Result<ServiceResponse<impl MessageBody>, Error> {
-    // Attempt to get the "Authorization" header from the request.
-    let header = req.headers().get(header::AUTHORIZATION);
     // --- Pre-processing ---
     // This part runs before the request is passed to the handler.
+    let jwt_settings = req
+        .app_data::<web::Data<JwtSettings>>()
+        .cloned()
+        .ok_or_else(|| AppError::internal("JWT settings are not configured"))?;
+    let token = bearer_token(&req)?;
 
-    match header {
-        Some(header) => {
-            let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
-            println!("Token header {:?}", header.to_str().unwrap_or(""));
-
-            let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS512);
-
-            validation.required_spec_claims = HashSet::new();
-            validation.validate_aud = false;
-
-            let decode_token = decode::<Claims>(
-                header.to_str().unwrap_or(""),
-                &DecodingKey::from_secret(jwt_secret.as_ref()),
-                &validation,
-            );
-
-            match decode_token {
-                Ok(token_data) => {
-                    req.extensions_mut().insert(AuthenticatedUser {
-                        id: token_data.claims.sub,
-                    });
-                }
-                Err(e) => {
-                    eprintln!("JWT Decode Error: {:?}", e);
-                    panic!("Failed to decode token: {}", e);
-                }
-            }
-        }
-        None => {
-            println!("Blocking request: Missing Authorization header.");
-            return Err(error::ErrorUnauthorized(
-                "Authorization header is required.",
-            ));
-        }
-    }
+    let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS512);
+    validation.required_spec_claims = HashSet::from(["exp".to_string()]);
+    validation.validate_exp = true;
+    validation.validate_aud = false;
 
-    println!("Request passed middleware pre-processing.");
+    let token_data = decode::<AccessTokenClaims>(
+        token,
+        &DecodingKey::from_secret(jwt_settings.secret.as_ref()),
+        &validation,
+    )
+    .map_err(|e| match e.kind() {
+        ErrorKind::ExpiredSignature => unauthorized(
+            "token_expired",
+            "The access token has expired",
+            Some("invalid_token"),
+        ),
+        _ => unauthorized(
+            "invalid_token",
+            "The access token is invalid",
+            Some("invalid_token"),
+        ),
+    })?;
 
-    let res = next.call(req).await?;
+    let claims = token_data.claims;
+
+    let session_repository = req
+        .app_data::<web::Data<dyn SessionRepository + Send + Sync>>()
+        .cloned()
+        .ok_or_else(|| AppError::internal("Session repository is not configured"))?;
+    if !session_repository
+        .is_active(claims.sid.clone())
+        .await
+        .map_err(AppError::from)?
+    {
+        return Err(unauthorized(
+            "session_revoked",
+            "The session has been revoked",
+            Some("invalid_token"),
+        ));
+    }
 
-    println!("Middleware post-processing the response.");
+    tracing::Span::current().record("user_id", claims.sub.as_str());
+    req.extensions_mut().insert(AuthenticatedUser {
+        id: claims.sub,
+        session_id: claims.sid,
+    });
 
-    Ok(res)
+    next.call(req).await
 }
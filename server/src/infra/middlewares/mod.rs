@@ -1 +1,4 @@
+pub mod check_admin_token;
 pub mod check_request_jwt;
+pub mod record_request_metrics;
+pub mod trace_request;
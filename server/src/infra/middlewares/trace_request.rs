@@ -0,0 +1,84 @@
+use std::time::Instant;
+
+use actix_web::{
+    Error,
+    body::{BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    error::InternalError,
+    http::header::{HeaderName, HeaderValue},
+    middleware::Next,
+};
+use tracing::{Instrument, field};
+
+use crate::infra::logging::redact_query;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Request ids sent by clients or proxies are kept when they look sane.
+fn incoming_request_id(req: &ServiceRequest) -> Option<String> {
+    let value = req.headers().get(REQUEST_ID_HEADER)?.to_str().ok()?;
+    let is_sane = !value.is_empty()
+        && value.len() <= 128
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    is_sane.then(|| value.to_string())
+}
+
+/// Runs every request inside a `request` span carrying its id, method, route
+/// and target (with secrets redacted from the query), later the user id (see
+/// `check_request_jwt`), and finally the status and latency, which are also
+/// logged once the response is ready. The id is echoed in the
+/// `x-request-id` response header.
+pub async fn trace_request(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let request_id = incoming_request_id(&req).unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let target = match req.query_string() {
+        "" => req.path().to_string(),
+        query => format!("{}?{}", req.path(), redact_query(query)),
+    };
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %req.method(),
+        route = req.match_pattern().as_deref().unwrap_or("unmatched"),
+        target = %target,
+        user_id = field::Empty,
+        status = field::Empty,
+        latency_ms = field::Empty,
+    );
+
+    let started = Instant::now();
+    let response = {
+        let _entered = span.enter();
+        next.call(req)
+    }
+    .instrument(span.clone())
+    .await;
+    // Nothing is awaited past this point, so the span can stay entered.
+    let _entered = span.enter();
+    // Errors from middlewares (e.g. a rejected token) only become responses
+    // further out, so they are rendered here to carry the request id too.
+    let mut response = match response {
+        Ok(response) => Ok(response.map_into_boxed_body()),
+        Err(e) => Err((e.error_response(), e)),
+    };
+    let (status, headers) = match &mut response {
+        Ok(response) => (response.status(), response.headers_mut()),
+        Err((error_response, _)) => (error_response.status(), error_response.headers_mut()),
+    };
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        headers.insert(REQUEST_ID_HEADER, value);
+    }
+    span.record("status", status.as_u16());
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    if status.is_server_error() {
+        tracing::error!("request failed");
+    } else {
+        tracing::info!("request completed");
+    }
+
+    response.map_err(|(error_response, e)| InternalError::from_response(e, error_response).into())
+}
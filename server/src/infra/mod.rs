@@ -1,3 +1,11 @@
 pub mod db;
+pub mod health;
+pub mod health_test;
 pub mod http;
+pub mod logging;
+pub mod logging_test;
+pub mod metrics;
+pub mod metrics_test;
 pub mod middlewares;
+pub mod notifiers;
+pub mod storage;
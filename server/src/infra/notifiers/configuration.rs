@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use crate::{
+    app::notifiers::notifier::Notifier,
+    infra::notifiers::{disabled_notifier::DisabledNotifier, file_notifier::FileNotifier},
+    settings::{NotifierBackendKind, NotifierSettings},
+};
+
+/// Builds the notifier selected by `settings.backend`.
+pub fn get_notifier(settings: &NotifierSettings) -> Arc<dyn Notifier + Send + Sync> {
+    match settings.backend {
+        NotifierBackendKind::File => Arc::new(FileNotifier::new(settings.outbox_path.clone())),
+        NotifierBackendKind::Disabled => Arc::new(DisabledNotifier),
+    }
+}
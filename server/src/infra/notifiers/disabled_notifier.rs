@@ -0,0 +1,16 @@
+use std::{future::Future, pin::Pin};
+
+use crate::app::notifiers::notifier::{Notification, Notifier, NotifierError};
+
+/// Used when no delivery channel is configured: every notification fails,
+/// so the caller logs it instead of the user silently never hearing back.
+pub struct DisabledNotifier;
+
+impl Notifier for DisabledNotifier {
+    fn send<'a>(
+        &'a self,
+        _notification: Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotifierError>> + Send + 'a>> {
+        Box::pin(async { Err(NotifierError("no notifier is configured".to_string())) })
+    }
+}
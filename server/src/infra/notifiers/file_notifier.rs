@@ -0,0 +1,51 @@
+use std::{future::Future, io::Write, path::PathBuf, pin::Pin};
+
+use actix_web::web;
+
+use crate::app::notifiers::notifier::{Notification, Notifier, NotifierError};
+
+/// Development stand-in for a real delivery channel: every notification is
+/// appended as one JSON line to a local outbox file. Only the kind is
+/// logged; the line carries the token.
+pub struct FileNotifier {
+    path: PathBuf,
+}
+
+impl FileNotifier {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Notifier for FileNotifier {
+    fn send<'a>(
+        &'a self,
+        notification: Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotifierError>> + Send + 'a>> {
+        Box::pin(async move {
+            let line = match &notification {
+                Notification::PasswordReset { email, token } => serde_json::json!({
+                    "kind": "password_reset",
+                    "to": email,
+                    "token": token,
+                    "sent_at": chrono::Utc::now(),
+                }),
+            };
+            tracing::info!(kind = %line["kind"], "notification written to the outbox");
+
+            // File I/O blocks, so it runs on the blocking pool rather than
+            // the worker answering the request.
+            let path = self.path.clone();
+            web::block(move || {
+                let mut file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)?;
+                writeln!(file, "{}", line)
+            })
+            .await
+            .map_err(|e| NotifierError(e.to_string()))?
+            .map_err(|e| NotifierError(e.to_string()))
+        })
+    }
+}
@@ -0,0 +1,3 @@
+pub mod configuration;
+pub mod disabled_notifier;
+pub mod file_notifier;
@@ -0,0 +1,128 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::app::storage::object_storage::{ObjectBytes, ObjectStorage, StorageError};
+
+/// Upper bound on cached URLs; stale entries are dropped first when it is hit.
+const MAX_CACHED_URLS: usize = 10_000;
+
+struct CachedUrl {
+    url: String,
+    /// A tenth of the lifetime before expiry, so a cached URL always has some
+    /// validity left when handed out.
+    refresh_at: Instant,
+}
+
+/// Reuses presigned `GET` URLs per key instead of signing on every read.
+/// Besides saving work, a stable URL lets browsers cache the image. Writes
+/// and deletes of a key drop its cached URL.
+pub struct CachedObjectStorage {
+    inner: Arc<dyn ObjectStorage + Send + Sync>,
+    urls: Mutex<HashMap<String, CachedUrl>>,
+}
+
+impl CachedObjectStorage {
+    pub fn new(inner: Arc<dyn ObjectStorage + Send + Sync>) -> Self {
+        Self {
+            inner,
+            urls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn cached(&self, key: &str) -> Option<String> {
+        let urls = self.urls.lock().unwrap();
+        urls.get(key)
+            .filter(|cached| Instant::now() < cached.refresh_at)
+            .map(|cached| cached.url.clone())
+    }
+
+    fn remember(&self, key: String, url: String, expires_in: Duration) {
+        let now = Instant::now();
+        let mut urls = self.urls.lock().unwrap();
+        if urls.len() >= MAX_CACHED_URLS {
+            urls.retain(|_, cached| now < cached.refresh_at);
+            if urls.len() >= MAX_CACHED_URLS {
+                urls.clear();
+            }
+        }
+        urls.insert(
+            key,
+            CachedUrl {
+                url,
+                refresh_at: now + expires_in * 9 / 10,
+            },
+        );
+    }
+
+    fn forget(&self, key: &str) {
+        self.urls.lock().unwrap().remove(key);
+    }
+}
+
+impl ObjectStorage for CachedObjectStorage {
+    fn presign_put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        self.inner.presign_put(key, content_type, expires_in)
+    }
+
+    fn presign_get<'a>(
+        &'a self,
+        key: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            if let Some(url) = self.cached(&key) {
+                return Ok(url);
+            }
+            let url = self.inner.presign_get(key.clone(), expires_in).await?;
+            self.remember(key, url.clone(), expires_in);
+            Ok(url)
+        })
+    }
+
+    fn get<'a>(
+        &'a self,
+        key: String,
+        max_bytes: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<ObjectBytes, StorageError>> + Send + 'a>> {
+        self.inner.get(key, max_bytes)
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        self.forget(&key);
+        self.inner.put(key, content_type, bytes)
+    }
+
+    fn delete<'a>(
+        &'a self,
+        key: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        self.forget(&key);
+        self.inner.delete(key)
+    }
+
+    fn delete_with_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        self.urls
+            .lock()
+            .unwrap()
+            .retain(|key, _| !key.starts_with(&prefix));
+        self.inner.delete_with_prefix(prefix)
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::{
+        app::storage::{mock_object_storage::MockObjectStorage, object_storage::ObjectStorage},
+        infra::storage::cached_object_storage::CachedObjectStorage,
+    };
+
+    const TTL: Duration = Duration::from_secs(3600);
+
+    fn setup() -> (Arc<MockObjectStorage>, CachedObjectStorage) {
+        let inner = Arc::new(MockObjectStorage::new());
+        let storage = CachedObjectStorage::new(inner.clone());
+        (inner, storage)
+    }
+
+    #[actix_web::test]
+    async fn test_presign_get_should_reuse_url_per_key() {
+        // Arrange
+        let (inner, storage) = setup();
+
+        // Act
+        let first = storage.presign_get("a".to_string(), TTL).await.unwrap();
+        let second = storage.presign_get("a".to_string(), TTL).await.unwrap();
+        storage.presign_get("b".to_string(), TTL).await.unwrap();
+
+        // Assert
+        assert_eq!(first, second);
+        assert_eq!(*inner.presigned_gets.lock().unwrap(), vec!["a", "b"]);
+    }
+
+    #[actix_web::test]
+    async fn test_presign_get_should_sign_again_close_to_expiry() {
+        // Arrange
+        let (inner, storage) = setup();
+
+        // Act
+        storage
+            .presign_get("a".to_string(), Duration::ZERO)
+            .await
+            .unwrap();
+        storage
+            .presign_get("a".to_string(), Duration::ZERO)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(inner.presigned_gets.lock().unwrap().len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_put_and_delete_should_drop_cached_url() {
+        // Arrange
+        let (inner, storage) = setup();
+        storage.presign_get("a".to_string(), TTL).await.unwrap();
+
+        // Act
+        storage
+            .put("a".to_string(), "image/png".to_string(), vec![1])
+            .await
+            .unwrap();
+        storage.presign_get("a".to_string(), TTL).await.unwrap();
+        storage.delete("a".to_string()).await.unwrap();
+        storage.presign_get("a".to_string(), TTL).await.unwrap();
+
+        // Assert
+        assert_eq!(inner.presigned_gets.lock().unwrap().len(), 3);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_with_prefix_should_drop_matching_cached_urls() {
+        // Arrange
+        let (inner, storage) = setup();
+        storage.presign_get("a-1".to_string(), TTL).await.unwrap();
+        storage.presign_get("b-1".to_string(), TTL).await.unwrap();
+
+        // Act
+        storage.delete_with_prefix("a-".to_string()).await.unwrap();
+        storage.presign_get("a-1".to_string(), TTL).await.unwrap();
+        storage.presign_get("b-1".to_string(), TTL).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            *inner.presigned_gets.lock().unwrap(),
+            vec!["a-1", "b-1", "a-1"]
+        );
+        assert_eq!(*inner.deleted_prefixes.lock().unwrap(), vec!["a-"]);
+    }
+
+    #[actix_web::test]
+    async fn test_presign_get_should_not_cache_failures() {
+        // Arrange
+        let (inner, storage) = setup();
+        inner.fail_with("unavailable");
+
+        // Act
+        let result = storage.presign_get("a".to_string(), TTL).await;
+        *inner.failure.lock().unwrap() = None;
+        let retried = storage.presign_get("a".to_string(), TTL).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(retried.is_ok());
+    }
+}
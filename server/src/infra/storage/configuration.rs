@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+
+use crate::{
+    app::storage::object_storage::ObjectStorage,
+    infra::storage::{
+        local_object_storage::LocalObjectStorage,
+        s3_object_storage::{S3Configuration, S3ObjectStorage},
+    },
+    settings::{S3Settings, StorageBackendKind, StorageSettings},
+};
+
+impl S3Configuration {
+    /// Expects settings that passed validation, which requires the endpoint
+    /// and keys for the `s3` backend.
+    pub fn from_settings(settings: &S3Settings) -> Self {
+        let required = |value: &Option<String>| value.clone().unwrap_or_default();
+        Self {
+            endpoint: required(&settings.endpoint),
+            region: settings.region.clone(),
+            bucket: settings.bucket.clone(),
+            access_key_id: required(&settings.access_key_id),
+            secret_access_key: required(&settings.secret_access_key),
+            path_style: settings.path_style,
+        }
+    }
+}
+
+/// The configured storage backend. The local one is kept concrete because
+/// the HTTP layer also serves its URLs.
+pub enum StorageBackend {
+    Local(Arc<LocalObjectStorage>),
+    S3(Arc<S3ObjectStorage>),
+}
+
+impl StorageBackend {
+    pub fn object_storage(&self) -> Arc<dyn ObjectStorage + Send + Sync> {
+        match self {
+            StorageBackend::Local(storage) => storage.clone(),
+            StorageBackend::S3(storage) => storage.clone(),
+        }
+    }
+}
+
+/// Builds the backend selected by `settings.backend`.
+pub fn get_storage_backend(settings: &StorageSettings) -> StorageBackend {
+    match settings.backend {
+        StorageBackendKind::S3 => StorageBackend::S3(Arc::new(S3ObjectStorage::new(
+            S3Configuration::from_settings(&settings.s3),
+        ))),
+        StorageBackendKind::Local => {
+            // Without a configured secret, URLs only stay valid for the
+            // lifetime of this process.
+            let secret = settings
+                .local
+                .secret
+                .clone()
+                .map(String::into_bytes)
+                .unwrap_or_else(|| {
+                    let mut bytes = vec![0u8; 32];
+                    OsRng.fill_bytes(&mut bytes);
+                    bytes
+                });
+            StorageBackend::Local(Arc::new(LocalObjectStorage::new(
+                settings.local.dir.clone(),
+                settings.local.base_url.clone(),
+                secret,
+            )))
+        }
+    }
+}
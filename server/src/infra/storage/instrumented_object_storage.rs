@@ -0,0 +1,92 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    app::storage::object_storage::{ObjectBytes, ObjectStorage, StorageError},
+    infra::metrics::Metrics,
+};
+
+/// Records the latency and failures of every operation of the wrapped
+/// storage. Sits below `CachedObjectStorage`, so cached URLs are not counted
+/// as calls.
+pub struct InstrumentedObjectStorage {
+    inner: Arc<dyn ObjectStorage + Send + Sync>,
+    metrics: Arc<Metrics>,
+}
+
+impl InstrumentedObjectStorage {
+    pub fn new(inner: Arc<dyn ObjectStorage + Send + Sync>, metrics: Arc<Metrics>) -> Self {
+        Self { inner, metrics }
+    }
+
+    fn observe<'a, T: 'a>(
+        &'a self,
+        operation: &'static str,
+        call: Pin<Box<dyn Future<Output = Result<T, StorageError>> + Send + 'a>>,
+    ) -> Pin<Box<dyn Future<Output = Result<T, StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = call.await;
+            self.metrics
+                .observe_object_storage(operation, result.is_ok(), started.elapsed());
+            result
+        })
+    }
+}
+
+impl ObjectStorage for InstrumentedObjectStorage {
+    fn presign_put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        self.observe(
+            "presign_put",
+            self.inner.presign_put(key, content_type, expires_in),
+        )
+    }
+
+    fn presign_get<'a>(
+        &'a self,
+        key: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        self.observe("presign_get", self.inner.presign_get(key, expires_in))
+    }
+
+    fn get<'a>(
+        &'a self,
+        key: String,
+        max_bytes: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<ObjectBytes, StorageError>> + Send + 'a>> {
+        self.observe("get", self.inner.get(key, max_bytes))
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        self.observe("put", self.inner.put(key, content_type, bytes))
+    }
+
+    fn delete<'a>(
+        &'a self,
+        key: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        self.observe("delete", self.inner.delete(key))
+    }
+
+    fn delete_with_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        self.observe("delete_with_prefix", self.inner.delete_with_prefix(prefix))
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        app::storage::{mock_object_storage::MockObjectStorage, object_storage::ObjectStorage},
+        infra::{
+            metrics::Metrics, storage::instrumented_object_storage::InstrumentedObjectStorage,
+        },
+    };
+
+    #[actix_web::test]
+    async fn test_operations_should_be_timed_and_failures_counted() {
+        // Arrange
+        let inner = Arc::new(MockObjectStorage::new());
+        let metrics = Arc::new(Metrics::new());
+        let storage = InstrumentedObjectStorage::new(inner.clone(), metrics.clone());
+
+        // Act
+        storage.get("a".to_string(), 1024).await.unwrap();
+        inner.fail_with("unavailable");
+        let result = storage.get("a".to_string(), 1024).await;
+
+        // Assert
+        assert!(result.is_err());
+        let rendered = metrics.render();
+        for line in [
+            r#"object_storage_operation_duration_seconds_count{operation="get",outcome="success"} 1"#,
+            r#"object_storage_operation_duration_seconds_count{operation="get",outcome="failure"} 1"#,
+            r#"object_storage_operation_failures_total{operation="get"} 1"#,
+        ] {
+            assert!(rendered.lines().any(|l| l == line), "missing `{}`", line);
+        }
+    }
+}
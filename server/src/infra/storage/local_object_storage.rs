@@ -0,0 +1,323 @@
+use std::{
+    fmt,
+    future::Future,
+    io::Read,
+    path::{Component, Path, PathBuf},
+    pin::Pin,
+    time::Duration,
+};
+
+use actix_web::web;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{
+    app::storage::object_storage::{ObjectBytes, ObjectStorage, StorageError},
+    infra::storage::sigv4::encode_path,
+};
+
+/// Why a request against a local storage URL was refused.
+#[derive(Debug, PartialEq)]
+pub enum SignatureError {
+    Invalid,
+    Expired,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::Invalid => write!(f, "The URL signature is invalid"),
+            SignatureError::Expired => write!(f, "The URL has expired"),
+        }
+    }
+}
+
+/// Backend keeping objects in a directory on disk, for offline development
+/// and self-hosting. The actix app serves the presigned URLs itself under
+/// `/storage/{key}`; each URL carries an expiry and an HMAC over the method,
+/// key, expiry and (for uploads) content type.
+///
+/// Objects live in `root/objects/{key}` and their content type in
+/// `root/meta/{key}`.
+pub struct LocalObjectStorage {
+    root: PathBuf,
+    base_url: String,
+    secret: Vec<u8>,
+}
+
+impl LocalObjectStorage {
+    pub fn new(root: impl Into<PathBuf>, base_url: String, secret: Vec<u8>) -> Self {
+        Self {
+            root: root.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            secret,
+        }
+    }
+
+    /// Refuses keys that are empty or would escape the storage root.
+    fn checked_key(key: &str) -> Result<&Path, StorageError> {
+        let relative = Path::new(key);
+        let is_contained = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_contained {
+            return Err(StorageError(format!("invalid object key {:?}", key)));
+        }
+        Ok(relative)
+    }
+
+    fn object_path(&self, key: &str) -> Result<PathBuf, StorageError> {
+        Ok(self.root.join("objects").join(Self::checked_key(key)?))
+    }
+
+    fn meta_path(&self, key: &str) -> Result<PathBuf, StorageError> {
+        Ok(self.root.join("meta").join(Self::checked_key(key)?))
+    }
+
+    fn mac(&self, method: &str, key: &str, expires: i64, content_type: &str) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any size");
+        mac.update(format!("{method}\n{key}\n{expires}\n{content_type}").as_bytes());
+        mac
+    }
+
+    /// Hex HMAC for a request on `key` valid until `expires` (unix seconds).
+    pub fn signature(&self, method: &str, key: &str, expires: i64, content_type: &str) -> String {
+        hex::encode(
+            self.mac(method, key, expires, content_type)
+                .finalize()
+                .into_bytes(),
+        )
+    }
+
+    fn signed_url(
+        &self,
+        method: &str,
+        key: &str,
+        content_type: &str,
+        expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        Self::checked_key(key)?;
+        let expires = Utc::now().timestamp() + expires_in.as_secs() as i64;
+        let signature = self.signature(method, key, expires, content_type);
+        Ok(format!(
+            "{}/storage/{}?expires={}&signature={}",
+            self.base_url,
+            encode_path(key),
+            expires,
+            signature
+        ))
+    }
+
+    /// Checks a request against the signature from its URL. `content_type`
+    /// is the request's `Content-Type` for uploads and empty for downloads.
+    pub fn verify(
+        &self,
+        method: &str,
+        key: &str,
+        expires: i64,
+        content_type: &str,
+        signature: &str,
+    ) -> Result<(), SignatureError> {
+        let signature = hex::decode(signature).map_err(|_| SignatureError::Invalid)?;
+        self.mac(method, key, expires, content_type)
+            .verify_slice(&signature)
+            .map_err(|_| SignatureError::Invalid)?;
+        if Utc::now().timestamp() > expires {
+            return Err(SignatureError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Stores `bytes` under `key`, replacing any previous object. The data is
+    /// written to a temporary file first so readers never see a partial file.
+    pub async fn write(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: impl AsRef<[u8]> + Send + 'static,
+    ) -> Result<(), StorageError> {
+        let object_path = self.object_path(key)?;
+        let meta_path = self.meta_path(key)?;
+        let content_type = content_type.to_string();
+        blocking(move || {
+            for path in [&object_path, &meta_path] {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+            }
+
+            let temporary = object_path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+            std::fs::write(&temporary, bytes)?;
+            std::fs::write(&meta_path, content_type)?;
+            std::fs::rename(&temporary, &object_path)
+        })
+        .await
+    }
+
+    /// Returns the object bytes and content type, or `None` when missing.
+    pub async fn read(&self, key: &str) -> Result<Option<(Vec<u8>, String)>, StorageError> {
+        let object_path = self.object_path(key)?;
+        let meta_path = self.meta_path(key)?;
+        blocking(move || {
+            let bytes = match std::fs::read(object_path) {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            let content_type = std::fs::read_to_string(meta_path)
+                .unwrap_or_else(|_| "application/octet-stream".to_string());
+            Ok(Some((bytes, content_type)))
+        })
+        .await
+    }
+}
+
+/// Runs file system calls on the blocking thread pool so a slow disk does
+/// not stall the worker serving other requests.
+async fn blocking<T, F>(operation: F) -> Result<T, StorageError>
+where
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    web::block(operation)
+        .await
+        .map_err(|e| StorageError(e.to_string()))?
+        .map_err(|e| StorageError(e.to_string()))
+}
+
+/// Like `remove_if_present`, but also removes directories with their content.
+fn remove_all_if_present(path: &Path) -> std::io::Result<()> {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => std::fs::remove_dir_all(path),
+        Ok(_) => std::fs::remove_file(path),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn remove_if_present(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+impl ObjectStorage for LocalObjectStorage {
+    fn presign_put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        let result = self.signed_url("PUT", &key, &content_type, expires_in);
+        Box::pin(async move { result })
+    }
+
+    fn presign_get<'a>(
+        &'a self,
+        key: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        let result = self.signed_url("GET", &key, "", expires_in);
+        Box::pin(async move { result })
+    }
+
+    fn get<'a>(
+        &'a self,
+        key: String,
+        max_bytes: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<ObjectBytes, StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            let object_path = self.object_path(&key)?;
+            blocking(move || {
+                let file = match std::fs::File::open(object_path) {
+                    Ok(file) => file,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                        return Ok(ObjectBytes::Missing);
+                    }
+                    Err(e) => return Err(e),
+                };
+                // Reads one byte past the limit to tell an oversized file apart.
+                let mut bytes = Vec::new();
+                file.take(max_bytes as u64 + 1).read_to_end(&mut bytes)?;
+                if bytes.len() > max_bytes {
+                    return Ok(ObjectBytes::TooLarge);
+                }
+                Ok(ObjectBytes::Found(bytes))
+            })
+            .await
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        Box::pin(async move { self.write(&key, &content_type, bytes).await })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        key: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            let object_path = self.object_path(&key)?;
+            let meta_path = self.meta_path(&key)?;
+            blocking(move || {
+                remove_if_present(&object_path)?;
+                remove_if_present(&meta_path)
+            })
+            .await
+        })
+    }
+
+    fn delete_with_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            // Keys map to paths, so only the entries of the directory the
+            // prefix ends in can match; a directory matching the last part
+            // holds deeper keys that all share the prefix.
+            let (directory, name_prefix) = match prefix.rsplit_once('/') {
+                Some((directory, name_prefix)) => (Some(directory), name_prefix.to_string()),
+                None => (None, prefix.clone()),
+            };
+            if name_prefix.is_empty() {
+                return Err(StorageError(format!(
+                    "invalid object key prefix {:?}",
+                    prefix
+                )));
+            }
+            let (objects_dir, meta_dir) = match directory {
+                Some(directory) => (self.object_path(directory)?, self.meta_path(directory)?),
+                None => (self.root.join("objects"), self.root.join("meta")),
+            };
+            blocking(move || {
+                let entries = match std::fs::read_dir(&objects_dir) {
+                    Ok(entries) => entries,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+                    Err(e) => return Err(e),
+                };
+                for entry in entries {
+                    let name = entry?.file_name();
+                    if name
+                        .to_str()
+                        .is_some_and(|name| name.starts_with(&name_prefix))
+                    {
+                        remove_all_if_present(&objects_dir.join(&name))?;
+                        remove_all_if_present(&meta_dir.join(&name))?;
+                    }
+                }
+                Ok(())
+            })
+            .await
+        })
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use reqwest::Url;
+
+    use crate::{
+        app::storage::object_storage::{ObjectBytes, ObjectStorage},
+        infra::storage::local_object_storage::{LocalObjectStorage, SignatureError},
+    };
+
+    fn storage() -> LocalObjectStorage {
+        LocalObjectStorage::new(
+            std::env::temp_dir().join(format!("local-storage-{}", uuid::Uuid::new_v4())),
+            "http://localhost:3000/".to_string(),
+            b"secret".to_vec(),
+        )
+    }
+
+    fn signed_query(url: &str) -> (i64, String) {
+        let url = Url::parse(url).unwrap();
+        let value = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+                .unwrap()
+        };
+        (value("expires").parse().unwrap(), value("signature"))
+    }
+
+    #[actix_web::test]
+    async fn test_presign_put_should_be_verified_with_the_same_content_type() {
+        // Arrange
+        let storage = storage();
+
+        // Act
+        let url = storage
+            .presign_put(
+                "user-1/avatar".to_string(),
+                "image/png".to_string(),
+                Duration::from_secs(60),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(url.starts_with("http://localhost:3000/storage/user-1/avatar?expires="));
+        let (expires, signature) = signed_query(&url);
+        assert_eq!(
+            storage.verify("PUT", "user-1/avatar", expires, "image/png", &signature),
+            Ok(())
+        );
+        assert_eq!(
+            storage.verify("PUT", "user-1/avatar", expires, "image/gif", &signature),
+            Err(SignatureError::Invalid)
+        );
+        assert_eq!(
+            storage.verify("GET", "user-1/avatar", expires, "", &signature),
+            Err(SignatureError::Invalid)
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_verify_should_reject_tampered_and_expired_urls() {
+        // Arrange
+        let storage = storage();
+        let url = storage
+            .presign_get("avatar".to_string(), Duration::from_secs(60))
+            .await
+            .unwrap();
+        let (expires, signature) = signed_query(&url);
+
+        // Act
+        let other_key = storage.verify("GET", "other", expires, "", &signature);
+        let extended = storage.verify("GET", "avatar", expires + 60, "", &signature);
+        let not_hex = storage.verify("GET", "avatar", expires, "", "zz");
+
+        // Assert
+        assert_eq!(other_key, Err(SignatureError::Invalid));
+        assert_eq!(extended, Err(SignatureError::Invalid));
+        assert_eq!(not_hex, Err(SignatureError::Invalid));
+    }
+
+    #[test]
+    fn test_verify_should_reject_an_expired_signature() {
+        // Arrange
+        let storage = storage();
+        let expires = Utc::now().timestamp() - 1;
+        let signature = storage.signature("GET", "avatar", expires, "");
+
+        // Act
+        let result = storage.verify("GET", "avatar", expires, "", &signature);
+
+        // Assert
+        assert_eq!(result, Err(SignatureError::Expired));
+    }
+
+    #[actix_web::test]
+    async fn test_write_read_and_delete_should_round_trip_an_object() {
+        // Arrange
+        let storage = storage();
+
+        // Act
+        storage
+            .write("user-1/avatar", "image/png", b"png")
+            .await
+            .unwrap();
+        let stored = storage.read("user-1/avatar").await.unwrap();
+        storage.delete("user-1/avatar".to_string()).await.unwrap();
+
+        // Assert
+        assert_eq!(stored, Some((b"png".to_vec(), "image/png".to_string())));
+        assert_eq!(storage.read("user-1/avatar").await.unwrap(), None);
+        assert!(storage.delete("user-1/avatar".to_string()).await.is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_delete_with_prefix_should_only_remove_matching_keys() {
+        // Arrange
+        let storage = storage();
+        for key in [
+            "user-1-avatar",
+            "user-1-upload",
+            "user-1-dir/nested",
+            "user-10-avatar",
+        ] {
+            storage.write(key, "image/png", b"png").await.unwrap();
+        }
+
+        // Act
+        let result = storage.delete_with_prefix("user-1-".to_string()).await;
+
+        // Assert
+        assert!(result.is_ok());
+        for key in ["user-1-avatar", "user-1-upload", "user-1-dir/nested"] {
+            assert_eq!(storage.read(key).await.unwrap(), None);
+        }
+        assert!(storage.read("user-10-avatar").await.unwrap().is_some());
+        assert!(
+            storage
+                .delete_with_prefix("user-1-".to_string())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_should_stop_at_the_size_limit() {
+        // Arrange
+        let storage = storage();
+        storage
+            .write("user-1/avatar", "image/png", b"png")
+            .await
+            .unwrap();
+
+        // Act
+        let within = storage.get("user-1/avatar".to_string(), 3).await.unwrap();
+        let over = storage.get("user-1/avatar".to_string(), 2).await.unwrap();
+        let missing = storage.get("user-2/avatar".to_string(), 3).await.unwrap();
+
+        // Assert
+        assert_eq!(within, ObjectBytes::Found(b"png".to_vec()));
+        assert_eq!(over, ObjectBytes::TooLarge);
+        assert_eq!(missing, ObjectBytes::Missing);
+    }
+
+    #[actix_web::test]
+    async fn test_write_should_refuse_keys_outside_the_root() {
+        // Arrange
+        let storage = storage();
+
+        // Act
+        let result = storage.write("../escape", "image/png", b"png").await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,13 @@
+pub mod cached_object_storage;
+pub mod cached_object_storage_test;
+pub mod configuration;
+pub mod instrumented_object_storage;
+pub mod instrumented_object_storage_test;
+pub mod local_object_storage;
+pub mod local_object_storage_test;
+pub mod resilience;
+pub mod resilience_test;
+pub mod s3_object_storage;
+pub mod s3_object_storage_test;
+pub mod sigv4;
+pub mod sigv4_test;
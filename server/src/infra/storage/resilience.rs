@@ -0,0 +1,119 @@
+use std::{
+    future::Future,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::app::storage::object_storage::StorageError;
+
+/// Outcome of a failed attempt, deciding whether it is worth trying again.
+#[derive(Debug)]
+pub enum AttemptError {
+    /// Network errors, timeouts, `429` and `5xx` answers.
+    Retryable(StorageError),
+    /// The service answered and another try would get the same answer.
+    Fatal(StorageError),
+}
+
+/// Stops calling a service that keeps failing. After `threshold` operations
+/// fail in a row the circuit opens and calls fail fast for `cooldown`; then a
+/// single trial call decides whether it closes again.
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    /// Whether a call may go through right now.
+    pub fn allow(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match state.open_until {
+            Some(open_until) if Instant::now() < open_until => false,
+            Some(_) => {
+                // Half open: let this call through and keep the others out
+                // until it reports back.
+                state.open_until = Some(Instant::now() + self.cooldown);
+                true
+            }
+            None => true,
+        }
+    }
+
+    pub fn record_success(&self) {
+        *self.state.lock().unwrap() = BreakerState::default();
+    }
+
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.threshold {
+            state.open_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+}
+
+/// Bounded retries with exponential backoff: `base_delay`, then twice that,
+/// and so on between attempts.
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Runs `attempt` until it succeeds, fails fatally or runs out of
+    /// attempts. The whole operation counts once towards `breaker`.
+    pub async fn run<T, F, Fut>(
+        &self,
+        breaker: &CircuitBreaker,
+        mut attempt: F,
+    ) -> Result<T, StorageError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AttemptError>>,
+    {
+        if !breaker.allow() {
+            return Err(StorageError(
+                "circuit open after repeated failures".to_string(),
+            ));
+        }
+
+        let mut delay = self.base_delay;
+        for attempt_number in 1..=self.max_attempts {
+            match attempt().await {
+                Ok(value) => {
+                    breaker.record_success();
+                    return Ok(value);
+                }
+                Err(AttemptError::Fatal(e)) => {
+                    breaker.record_success();
+                    return Err(e);
+                }
+                Err(AttemptError::Retryable(e)) if attempt_number == self.max_attempts => {
+                    breaker.record_failure();
+                    return Err(e);
+                }
+                Err(AttemptError::Retryable(_)) => {
+                    actix_web::rt::time::sleep(delay).await;
+                    delay *= 2;
+                }
+            }
+        }
+        breaker.record_failure();
+        Err(StorageError("no attempts were made".to_string()))
+    }
+}
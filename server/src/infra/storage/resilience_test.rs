@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use crate::{
+        app::storage::object_storage::StorageError,
+        infra::storage::resilience::{AttemptError, CircuitBreaker, RetryPolicy},
+    };
+
+    const POLICY: RetryPolicy = RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+    };
+
+    fn retryable() -> AttemptError {
+        AttemptError::Retryable(StorageError("503".to_string()))
+    }
+
+    #[actix_web::test]
+    async fn test_run_should_retry_transient_failures_until_success() {
+        // Arrange
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(60));
+        let attempts = AtomicU32::new(0);
+
+        // Act
+        let result = POLICY
+            .run(&breaker, || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 | 1 => Err(retryable()),
+                    _ => Ok("done"),
+                }
+            })
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(breaker.allow());
+    }
+
+    #[actix_web::test]
+    async fn test_run_should_not_retry_fatal_failures() {
+        // Arrange
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(60));
+        let attempts = AtomicU32::new(0);
+
+        // Act
+        let result: Result<(), StorageError> = POLICY
+            .run(&breaker, || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(AttemptError::Fatal(StorageError("403".to_string())))
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(breaker.allow());
+    }
+
+    #[actix_web::test]
+    async fn test_run_should_open_circuit_after_repeated_failures() {
+        // Arrange
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        let attempts = AtomicU32::new(0);
+        let failing = || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(retryable())
+        };
+
+        // Act
+        POLICY.run(&breaker, failing).await.unwrap_err();
+        POLICY.run(&breaker, failing).await.unwrap_err();
+        let result = POLICY.run(&breaker, failing).await;
+
+        // Assert
+        assert!(result.unwrap_err().0.contains("circuit open"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn test_circuit_should_let_one_trial_through_after_cooldown() {
+        // Arrange
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(20));
+        breaker.record_failure();
+        assert!(!breaker.allow());
+
+        // Act
+        std::thread::sleep(Duration::from_millis(30));
+
+        // Assert
+        assert!(breaker.allow());
+        assert!(!breaker.allow());
+        breaker.record_success();
+        assert!(breaker.allow());
+    }
+}
@@ -0,0 +1,341 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use reqwest::{Method, StatusCode, Url, header::CONTENT_TYPE};
+
+use crate::{
+    app::storage::object_storage::{ObjectBytes, ObjectStorage, StorageError},
+    infra::storage::{
+        resilience::{AttemptError, CircuitBreaker, RetryPolicy},
+        sigv4::{Credentials, PresignRequest, encode_path, presign},
+    },
+};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+/// Covers the whole request, body included.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const RETRY_POLICY: RetryPolicy = RetryPolicy {
+    max_attempts: 3,
+    base_delay: Duration::from_millis(100),
+};
+/// Failed operations in a row before calls fail fast for `BREAKER_COOLDOWN`.
+const BREAKER_THRESHOLD: u32 = 5;
+const BREAKER_COOLDOWN: Duration = Duration::from_secs(30);
+/// Lifetime of the URLs the server signs for its own requests.
+const SERVER_REQUEST_TTL: Duration = Duration::from_secs(60);
+
+pub struct S3Configuration {
+    /// Service root, e.g. `http://localhost:9000` for MinIO or the R2
+    /// account endpoint.
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// `true` puts the bucket in the path (`endpoint/bucket/key`), which MinIO
+    /// and R2 accept; `false` uses `bucket.endpoint/key`.
+    pub path_style: bool,
+}
+
+/// Presigns requests for any S3 compatible service (AWS S3, Cloudflare R2,
+/// MinIO) without going through a separate service. The requests the server
+/// makes itself share one client with timeouts, are retried on transient
+/// failures and go through a circuit breaker.
+pub struct S3ObjectStorage {
+    configuration: S3Configuration,
+    client: reqwest::Client,
+    breaker: CircuitBreaker,
+}
+
+impl S3ObjectStorage {
+    pub fn new(configuration: S3Configuration) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to build the S3 HTTP client");
+        Self {
+            configuration,
+            client,
+            breaker: CircuitBreaker::new(BREAKER_THRESHOLD, BREAKER_COOLDOWN),
+        }
+    }
+
+    /// Sends a request signed by the server. Any answer the service gives is
+    /// returned except `429` and `5xx`, which are retried.
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        query: &[(&str, String)],
+        content_type: Option<&str>,
+        body: Option<&[u8]>,
+    ) -> Result<reqwest::Response, StorageError> {
+        RETRY_POLICY
+            .run(&self.breaker, || async {
+                let url = self
+                    .presign(
+                        method.as_str(),
+                        key,
+                        query,
+                        content_type,
+                        SERVER_REQUEST_TTL,
+                    )
+                    .map_err(AttemptError::Fatal)?;
+                let mut request = self.client.request(method.clone(), url);
+                if let Some(content_type) = content_type {
+                    request = request.header(CONTENT_TYPE, content_type);
+                }
+                if let Some(body) = body {
+                    request = request.body(body.to_vec());
+                }
+
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|e| AttemptError::Retryable(transport_error(e)))?;
+                let status = response.status();
+                if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+                    return Err(AttemptError::Retryable(StorageError(format!(
+                        "{} {} returned {}",
+                        method, key, status
+                    ))));
+                }
+                Ok(response)
+            })
+            .await
+    }
+
+    fn object_url(&self, key: &str) -> Result<Url, StorageError> {
+        let mut url = Url::parse(&self.configuration.endpoint)
+            .map_err(|e| StorageError(format!("invalid S3 endpoint: {}", e)))?;
+        let path = if self.configuration.path_style {
+            format!("/{}/{}", self.configuration.bucket, encode_path(key))
+        } else {
+            let host = format!(
+                "{}.{}",
+                self.configuration.bucket,
+                url.host_str().unwrap_or_default()
+            );
+            url.set_host(Some(&host))
+                .map_err(|e| StorageError(format!("invalid S3 endpoint: {}", e)))?;
+            format!("/{}", encode_path(key))
+        };
+        url.set_path(&path);
+        Ok(url)
+    }
+
+    fn presign(
+        &self,
+        method: &str,
+        key: &str,
+        query: &[(&str, String)],
+        content_type: Option<&str>,
+        expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        let url = self.object_url(key)?;
+        let credentials = Credentials {
+            access_key_id: &self.configuration.access_key_id,
+            secret_access_key: &self.configuration.secret_access_key,
+            region: &self.configuration.region,
+        };
+        Ok(presign(
+            &credentials,
+            &PresignRequest {
+                method,
+                url: &url,
+                content_type,
+                query,
+                expires_in_secs: expires_in.as_secs(),
+                now: chrono::Utc::now(),
+            },
+        ))
+    }
+}
+
+impl S3ObjectStorage {
+    /// Keys starting with `prefix`, through `ListObjectsV2` pages of up to
+    /// 1000 keys each.
+    async fn list_keys(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let mut keys = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let mut query = vec![
+                ("list-type", "2".to_string()),
+                ("prefix", prefix.to_string()),
+            ];
+            if let Some(token) = continuation_token {
+                query.push(("continuation-token", token));
+            }
+            // An empty key addresses the bucket itself.
+            let response = self.send(Method::GET, "", &query, None, None).await?;
+            let body = ensure_success(response, "LIST", prefix)?
+                .text()
+                .await
+                .map_err(transport_error)?;
+            let page = parse_list_objects(&body);
+            keys.extend(page.keys);
+            continuation_token = page.next_continuation_token;
+            if continuation_token.is_none() {
+                return Ok(keys);
+            }
+        }
+    }
+}
+
+impl ObjectStorage for S3ObjectStorage {
+    fn presign_put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        let result = self.presign("PUT", &key, &[], Some(&content_type), expires_in);
+        Box::pin(async move { result })
+    }
+
+    fn presign_get<'a>(
+        &'a self,
+        key: String,
+        expires_in: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<String, StorageError>> + Send + 'a>> {
+        let result = self.presign("GET", &key, &[], None, expires_in);
+        Box::pin(async move { result })
+    }
+
+    fn get<'a>(
+        &'a self,
+        key: String,
+        max_bytes: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<ObjectBytes, StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = self.send(Method::GET, &key, &[], None, None).await?;
+            if response.status() == StatusCode::NOT_FOUND {
+                return Ok(ObjectBytes::Missing);
+            }
+            let mut response = ensure_success(response, "GET", &key)?;
+            if response
+                .content_length()
+                .is_some_and(|length| length > max_bytes as u64)
+            {
+                return Ok(ObjectBytes::TooLarge);
+            }
+            // The length header may be missing, so the body is capped too.
+            let mut bytes = Vec::new();
+            while let Some(chunk) = response.chunk().await.map_err(transport_error)? {
+                if bytes.len() + chunk.len() > max_bytes {
+                    return Ok(ObjectBytes::TooLarge);
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+            Ok(ObjectBytes::Found(bytes))
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = self
+                .send(Method::PUT, &key, &[], Some(&content_type), Some(&bytes))
+                .await?;
+            ensure_success(response, "PUT", &key)?;
+            Ok(())
+        })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        key: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            // S3 answers 204 whether or not the key existed.
+            let response = self.send(Method::DELETE, &key, &[], None, None).await?;
+            ensure_success(response, "DELETE", &key)?;
+            Ok(())
+        })
+    }
+
+    fn delete_with_prefix<'a>(
+        &'a self,
+        prefix: String,
+    ) -> Pin<Box<dyn Future<Output = Result<(), StorageError>> + Send + 'a>> {
+        Box::pin(async move {
+            for key in self.list_keys(&prefix).await? {
+                self.delete(key).await?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// One page of a `ListObjectsV2` answer.
+#[derive(Debug, PartialEq)]
+pub struct ListObjectsPage {
+    pub keys: Vec<String>,
+    /// Set while more keys are left to list.
+    pub next_continuation_token: Option<String>,
+}
+
+/// Reads the keys and continuation token out of a `ListObjectsV2` XML body.
+/// Only `Key` elements sit inside `Contents`, so they need no nesting rules.
+pub fn parse_list_objects(body: &str) -> ListObjectsPage {
+    let is_truncated = xml_elements(body, "IsTruncated").any(|value| value == "true");
+    ListObjectsPage {
+        keys: xml_elements(body, "Key").collect(),
+        next_continuation_token: xml_elements(body, "NextContinuationToken")
+            .next()
+            .filter(|_| is_truncated),
+    }
+}
+
+/// Unescaped text of every `<tag>...</tag>` element in `body`.
+fn xml_elements<'a>(body: &'a str, tag: &str) -> impl Iterator<Item = String> + 'a {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let mut rest = body;
+    std::iter::from_fn(move || {
+        let start = rest.find(&open)? + open.len();
+        let end = start + rest[start..].find(&close)?;
+        let text = &rest[start..end];
+        rest = &rest[end + close.len()..];
+        Some(
+            text.replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&amp;", "&"),
+        )
+    })
+}
+
+/// Describes a failed exchange with its causes but without the URL, whose
+/// signature must stay out of errors and logs.
+fn transport_error(error: reqwest::Error) -> StorageError {
+    let error = error.without_url();
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(&error);
+    while let Some(cause) = source {
+        message.push_str(&format!(": {}", cause));
+        source = cause.source();
+    }
+    StorageError(message)
+}
+
+fn ensure_success(
+    response: reqwest::Response,
+    method: &str,
+    key: &str,
+) -> Result<reqwest::Response, StorageError> {
+    if !response.status().is_success() {
+        return Err(StorageError(format!(
+            "{} {} returned {}",
+            method,
+            key,
+            response.status()
+        )));
+    }
+    Ok(response)
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::infra::storage::s3_object_storage::{ListObjectsPage, parse_list_objects};
+
+    #[test]
+    fn test_parse_list_objects_should_read_keys_and_continuation_token() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>todo-tasks</Name>
+  <Prefix>user-</Prefix>
+  <KeyCount>2</KeyCount>
+  <MaxKeys>2</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <Contents><Key>user-1</Key><Size>10</Size></Contents>
+  <Contents><Key>user-&amp;2</Key><Size>20</Size></Contents>
+  <NextContinuationToken>1ueGcxLPRx1Tr/XYExHnhbYLgveDs2J/wm36Hy4vbOwM=</NextContinuationToken>
+</ListBucketResult>"#;
+
+        let page = parse_list_objects(body);
+
+        assert_eq!(
+            page,
+            ListObjectsPage {
+                keys: vec!["user-1".to_string(), "user-&2".to_string()],
+                next_continuation_token: Some(
+                    "1ueGcxLPRx1Tr/XYExHnhbYLgveDs2J/wm36Hy4vbOwM=".to_string()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_list_objects_on_last_page_should_stop() {
+        let body = "<ListBucketResult><IsTruncated>false</IsTruncated></ListBucketResult>";
+
+        let page = parse_list_objects(body);
+
+        assert_eq!(
+            page,
+            ListObjectsPage {
+                keys: vec![],
+                next_continuation_token: None,
+            }
+        );
+    }
+}
@@ -1,19 +1,92 @@
-use std::net::TcpListener;
+use std::{io::Write, net::TcpListener};
 
-use crate::env::get_env_var;
+use crate::{
+    command::{Command, run_backfill_identicons_command, run_migrate_command},
+    infra::{
+        db::{
+            configuration::{DatabaseConfiguration, get_configuration},
+            migrations::run_pending_migrations,
+        },
+        logging::init_logging,
+    },
+    settings::Settings,
+};
 pub mod app;
-pub mod env;
+pub mod command;
+pub mod command_test;
 pub mod infra;
+pub mod settings;
+pub mod settings_test;
 pub mod startup;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    let port = get_env_var("PORT")
-        .expect("PORT must be set")
-        .parse::<u16>()
-        .expect("PORT must be a valid u16");
-    let address = format!("127.0.0.1:{}", port);
-    let listener = TcpListener::bind(address)?;
-
-    startup::run(listener)?.await
+    // Logging is configured by the settings, so problems found before they
+    // are loaded can only go to stderr.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = Command::parse(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+    let settings = match Settings::load() {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let log_level = init_logging(&settings.log);
+
+    let pool = get_configuration(&DatabaseConfiguration::from_settings(&settings.database))
+        .await
+        .expect("Failed to connect to the database");
+
+    if let Command::Migrate(migrate_command) = command {
+        if let Err(e) = run_migrate_command(migrate_command, &pool).await {
+            tracing::error!(error = %e, "migration failed");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if command == Command::BackfillIdenticons {
+        if let Err(e) = run_backfill_identicons_command(&settings, &pool).await {
+            tracing::error!(error = %e, "identicon backfill failed");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if settings.database.migrate_on_startup {
+        match run_pending_migrations(&pool).await {
+            Ok(applied) => {
+                for migration in applied {
+                    tracing::info!(
+                        version = migration.version,
+                        description = %migration.description,
+                        "migration applied"
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "migration failed");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let listener =
+        TcpListener::bind((settings.server.bind_address.as_str(), settings.server.port))?;
+    tracing::info!(
+        profile = ?settings.profile,
+        address = %listener.local_addr()?,
+        "server listening"
+    );
+    startup::run(listener, pool.clone(), settings, log_level)?.await?;
+
+    // The server only returns once requests are drained or dropped, so no
+    // connection is in use anymore. Metrics are scraped, nothing to push.
+    pool.close().await;
+    tracing::info!("server stopped");
+    std::io::stdout().flush()
 }
@@ -0,0 +1,481 @@
+use std::{collections::HashMap, fmt, path::Path};
+
+use config::{Config, File, FileFormat};
+use serde::Deserialize;
+use tracing_subscriber::EnvFilter;
+
+use crate::app::use_cases::{
+    request_password_reset_use_case::DEFAULT_PASSWORD_RESET_TOKEN_TTL_SECS,
+    session_tokens::{DEFAULT_ACCESS_TOKEN_TTL_SECS, DEFAULT_REFRESH_TOKEN_TTL_SECS},
+};
+
+/// Environment variables that override a setting, with the key they set.
+/// Values are parsed into the type of the setting they override.
+const ENV_OVERRIDES: &[(&str, &str)] = &[
+    ("BIND_ADDRESS", "server.bind_address"),
+    ("PORT", "server.port"),
+    ("SHUTDOWN_TIMEOUT_SECS", "server.shutdown_timeout_secs"),
+    ("DATABASE_URL", "database.url"),
+    ("DATABASE_MAX_CONNECTIONS", "database.max_connections"),
+    ("DATABASE_MIN_CONNECTIONS", "database.min_connections"),
+    (
+        "DATABASE_ACQUIRE_TIMEOUT_SECS",
+        "database.acquire_timeout_secs",
+    ),
+    ("DATABASE_IDLE_TIMEOUT_SECS", "database.idle_timeout_secs"),
+    ("DATABASE_MAX_LIFETIME_SECS", "database.max_lifetime_secs"),
+    ("DATABASE_MIGRATE_ON_STARTUP", "database.migrate_on_startup"),
+    ("JWT_SECRET", "jwt.secret"),
+    ("ACCESS_TOKEN_TTL_SECS", "jwt.access_token_ttl_secs"),
+    ("REFRESH_TOKEN_TTL_SECS", "jwt.refresh_token_ttl_secs"),
+    (
+        "PASSWORD_RESET_TOKEN_TTL_SECS",
+        "jwt.password_reset_token_ttl_secs",
+    ),
+    ("CORS_MAX_AGE_SECS", "cors.max_age_secs"),
+    ("STORAGE_BACKEND", "storage.backend"),
+    ("LOCAL_STORAGE_DIR", "storage.local.dir"),
+    ("LOCAL_STORAGE_BASE_URL", "storage.local.base_url"),
+    ("LOCAL_STORAGE_SECRET", "storage.local.secret"),
+    (
+        "LOCAL_STORAGE_MAX_UPLOAD_BYTES",
+        "storage.local.max_upload_bytes",
+    ),
+    ("S3_ENDPOINT", "storage.s3.endpoint"),
+    ("S3_REGION", "storage.s3.region"),
+    ("S3_BUCKET", "storage.s3.bucket"),
+    ("S3_ACCESS_KEY_ID", "storage.s3.access_key_id"),
+    ("S3_SECRET_ACCESS_KEY", "storage.s3.secret_access_key"),
+    ("S3_PATH_STYLE", "storage.s3.path_style"),
+    ("DEFAULT_AVATAR", "default_avatar"),
+    ("NOTIFIER_BACKEND", "notifier.backend"),
+    ("NOTIFIER_OUTBOX_PATH", "notifier.outbox_path"),
+    ("LOG_LEVEL", "log.level"),
+    ("LOG_FORMAT", "log.format"),
+    ("ADMIN_TOKEN", "admin_token"),
+];
+
+/// Shortest JWT secret accepted by the `prod` profile.
+const PROD_MIN_JWT_SECRET_BYTES: usize = 32;
+
+/// Selects `config/{profile}.toml`; `prod` also enforces stricter checks.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Profile {
+    #[default]
+    Dev,
+    Test,
+    Prod,
+}
+
+impl Profile {
+    fn parse(value: &str) -> Result<Self, SettingsError> {
+        match value {
+            "dev" => Ok(Profile::Dev),
+            "test" => Ok(Profile::Test),
+            "prod" => Ok(Profile::Prod),
+            other => Err(SettingsError::Load(format!(
+                "APP_PROFILE must be `dev`, `test` or `prod`, got `{other}`"
+            ))),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SettingsError {
+    /// A file could not be read or a value has the wrong type.
+    Load(String),
+    /// Every setting that is missing or out of range.
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettingsError::Load(e) => write!(f, "Failed to load configuration: {}", e),
+            SettingsError::Invalid(problems) => {
+                write!(f, "Invalid configuration: {}", problems.join("; "))
+            }
+        }
+    }
+}
+
+impl From<config::ConfigError> for SettingsError {
+    fn from(error: config::ConfigError) -> Self {
+        SettingsError::Load(error.to_string())
+    }
+}
+
+/// Everything the server is configured with, read and validated once at
+/// startup and handed to the components that need it.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    #[serde(skip)]
+    pub profile: Profile,
+    pub server: ServerSettings,
+    pub database: DatabaseSettings,
+    pub jwt: JwtSettings,
+    pub cors: CorsSettings,
+    pub storage: StorageSettings,
+    /// Prefix registration used to give the avatar keys of users without a
+    /// picture; see `BackfillIdenticonsUseCase`.
+    pub default_avatar: Option<String>,
+    pub notifier: NotifierSettings,
+    pub log: LogSettings,
+    /// Bearer token for the `/admin` endpoints, which are disabled without
+    /// one.
+    pub admin_token: Option<String>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct ServerSettings {
+    pub bind_address: String,
+    pub port: u16,
+    /// How long in-flight requests may take to finish once a shutdown
+    /// starts; whatever is still running afterwards is dropped.
+    pub shutdown_timeout_secs: u64,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            bind_address: "127.0.0.1".to_string(),
+            port: 3000,
+            shutdown_timeout_secs: 30,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct DatabaseSettings {
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout_secs: u64,
+    /// A value of 0 disables the limit.
+    pub idle_timeout_secs: u64,
+    /// A value of 0 disables the limit.
+    pub max_lifetime_secs: u64,
+    /// Apply pending migrations before serving requests.
+    pub migrate_on_startup: bool,
+}
+
+impl Default for DatabaseSettings {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            max_connections: 10,
+            min_connections: 0,
+            acquire_timeout_secs: 5,
+            idle_timeout_secs: 600,
+            max_lifetime_secs: 1800,
+            migrate_on_startup: true,
+        }
+    }
+}
+
+/// Secret signing access tokens and the lifetimes of session and password
+/// reset tokens.
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct JwtSettings {
+    pub secret: String,
+    pub access_token_ttl_secs: i64,
+    pub refresh_token_ttl_secs: i64,
+    pub password_reset_token_ttl_secs: i64,
+}
+
+impl Default for JwtSettings {
+    fn default() -> Self {
+        Self {
+            secret: String::new(),
+            access_token_ttl_secs: DEFAULT_ACCESS_TOKEN_TTL_SECS,
+            refresh_token_ttl_secs: DEFAULT_REFRESH_TOKEN_TTL_SECS,
+            password_reset_token_ttl_secs: DEFAULT_PASSWORD_RESET_TOKEN_TTL_SECS,
+        }
+    }
+}
+
+impl JwtSettings {
+    pub fn access_token_ttl(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.access_token_ttl_secs)
+    }
+
+    pub fn refresh_token_ttl(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.refresh_token_ttl_secs)
+    }
+
+    pub fn password_reset_token_ttl(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.password_reset_token_ttl_secs)
+    }
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct CorsSettings {
+    /// Set from the environment as a comma separated `CORS_ALLOWED_ORIGINS`.
+    pub allowed_origins: Vec<String>,
+    pub max_age_secs: usize,
+}
+
+impl Default for CorsSettings {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec!["http://localhost:5173".to_string()],
+            max_age_secs: 3600,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackendKind {
+    #[default]
+    Local,
+    S3,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StorageSettings {
+    pub backend: StorageBackendKind,
+    pub local: LocalStorageSettings,
+    pub s3: S3Settings,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct LocalStorageSettings {
+    pub dir: String,
+    /// Public URL of this server, used in the URLs it signs.
+    pub base_url: String,
+    /// Without one, URLs only stay valid for the lifetime of the process.
+    pub secret: Option<String>,
+    pub max_upload_bytes: usize,
+}
+
+impl Default for LocalStorageSettings {
+    fn default() -> Self {
+        Self {
+            dir: "uploads".to_string(),
+            base_url: "http://localhost:3000".to_string(),
+            secret: None,
+            max_upload_bytes: 5 * 1024 * 1024,
+        }
+    }
+}
+
+/// Only read with the `s3` backend, which requires the endpoint and keys.
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct S3Settings {
+    pub endpoint: Option<String>,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: Option<String>,
+    pub secret_access_key: Option<String>,
+    pub path_style: bool,
+}
+
+impl Default for S3Settings {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            region: "us-east-1".to_string(),
+            bucket: "todo-tasks".to_string(),
+            access_key_id: None,
+            secret_access_key: None,
+            path_style: true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifierBackendKind {
+    /// Appends notifications, tokens included, to a local outbox file.
+    #[default]
+    File,
+    /// Drops notifications; the only choice in prod until a mail provider
+    /// is wired in.
+    Disabled,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct NotifierSettings {
+    pub backend: NotifierBackendKind,
+    /// Only read with the `file` backend.
+    pub outbox_path: String,
+}
+
+impl Default for NotifierSettings {
+    fn default() -> Self {
+        Self {
+            backend: NotifierBackendKind::File,
+            outbox_path: "outbox.log".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// One JSON object per line, for log collectors.
+    #[default]
+    Json,
+    /// Human readable lines, for local development.
+    Pretty,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default)]
+pub struct LogSettings {
+    /// `EnvFilter` directives such as `info` or `info,sqlx=warn`. Can be
+    /// changed while running through `PUT /admin/log-level`.
+    pub level: String,
+    pub format: LogFormat,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            format: LogFormat::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Loads the settings of the process: `.env` is read into the
+    /// environment, then [`Settings::from_sources`] layers the files in
+    /// `CONFIG_DIR` (default `config`) and the environment.
+    pub fn load() -> Result<Self, SettingsError> {
+        dotenv::dotenv().ok();
+        let env: HashMap<String, String> = std::env::vars().collect();
+        let config_dir = env
+            .get("CONFIG_DIR")
+            .cloned()
+            .unwrap_or_else(|| "config".to_string());
+        Self::from_sources(Path::new(&config_dir), &env)
+    }
+
+    /// Builds the settings from, by increasing precedence: the defaults,
+    /// `{config_dir}/default.toml`, `{config_dir}/{profile}.toml` and the
+    /// variables in `env`. The profile comes from `APP_PROFILE` (`dev`,
+    /// `test` or `prod`, default `dev`). Both files are optional.
+    pub fn from_sources(
+        config_dir: &Path,
+        env: &HashMap<String, String>,
+    ) -> Result<Self, SettingsError> {
+        let profile = match env.get("APP_PROFILE") {
+            Some(value) => Profile::parse(value)?,
+            None => Profile::default(),
+        };
+
+        let mut builder = Config::builder()
+            .add_source(
+                File::from(config_dir.join("default.toml"))
+                    .format(FileFormat::Toml)
+                    .required(false),
+            )
+            .add_source(
+                File::from(config_dir.join(format!("{}.toml", profile.name())))
+                    .format(FileFormat::Toml)
+                    .required(false),
+            );
+        for (var, key) in ENV_OVERRIDES {
+            builder = builder.set_override_option(*key, env.get(*var).cloned())?;
+        }
+        if let Some(origins) = env.get("CORS_ALLOWED_ORIGINS") {
+            let origins: Vec<String> = origins
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(str::to_string)
+                .collect();
+            builder = builder.set_override("cors.allowed_origins", origins)?;
+        }
+
+        let mut settings: Settings = builder.build()?.try_deserialize()?;
+        settings.profile = profile;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    fn validate(&self) -> Result<(), SettingsError> {
+        let mut problems = Vec::new();
+        if self.database.url.is_empty() {
+            problems.push("DATABASE_URL must be set".to_string());
+        }
+        if self.jwt.secret.is_empty() {
+            problems.push("JWT_SECRET must be set".to_string());
+        } else if self.profile == Profile::Prod && self.jwt.secret.len() < PROD_MIN_JWT_SECRET_BYTES
+        {
+            problems.push(format!(
+                "JWT_SECRET must be at least {PROD_MIN_JWT_SECRET_BYTES} bytes in prod"
+            ));
+        }
+        for (name, ttl) in [
+            ("ACCESS_TOKEN_TTL_SECS", self.jwt.access_token_ttl_secs),
+            ("REFRESH_TOKEN_TTL_SECS", self.jwt.refresh_token_ttl_secs),
+            (
+                "PASSWORD_RESET_TOKEN_TTL_SECS",
+                self.jwt.password_reset_token_ttl_secs,
+            ),
+        ] {
+            if ttl <= 0 {
+                problems.push(format!("{name} must be positive"));
+            }
+        }
+        if EnvFilter::try_new(&self.log.level).is_err() {
+            problems.push(format!(
+                "LOG_LEVEL `{}` is not a valid filter",
+                self.log.level
+            ));
+        }
+        if self.database.min_connections > self.database.max_connections {
+            problems.push(
+                "DATABASE_MIN_CONNECTIONS must not exceed DATABASE_MAX_CONNECTIONS".to_string(),
+            );
+        }
+        match self.storage.backend {
+            StorageBackendKind::Local => {
+                if self.profile == Profile::Prod && self.storage.local.secret.is_none() {
+                    problems.push("LOCAL_STORAGE_SECRET must be set in prod".to_string());
+                }
+            }
+            StorageBackendKind::S3 => {
+                let s3 = &self.storage.s3;
+                for (name, value) in [
+                    ("S3_ENDPOINT", &s3.endpoint),
+                    ("S3_ACCESS_KEY_ID", &s3.access_key_id),
+                    ("S3_SECRET_ACCESS_KEY", &s3.secret_access_key),
+                ] {
+                    if value.is_none() {
+                        problems.push(format!("{name} must be set for the s3 backend"));
+                    }
+                }
+            }
+        }
+        if self.profile == Profile::Prod && self.notifier.backend == NotifierBackendKind::File {
+            problems.push("NOTIFIER_BACKEND must not be `file` in prod".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(SettingsError::Invalid(problems))
+        }
+    }
+}
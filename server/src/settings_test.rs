@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use crate::settings::{
+        LogFormat, NotifierBackendKind, Profile, Settings, SettingsError, StorageBackendKind,
+    };
+
+    fn config_dir(files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("settings-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, contents) in files {
+            std::fs::write(dir.join(name), contents).unwrap();
+        }
+        dir
+    }
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+        let mut env: HashMap<String, String> = [
+            ("DATABASE_URL", "postgres://localhost/tasks"),
+            ("JWT_SECRET", "secret"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        for (key, value) in vars {
+            env.insert(key.to_string(), value.to_string());
+        }
+        env
+    }
+
+    fn problems(result: Result<Settings, SettingsError>) -> Vec<String> {
+        match result {
+            Err(SettingsError::Invalid(problems)) => problems,
+            Err(e) => panic!("expected invalid settings, got {}", e),
+            Ok(_) => panic!("expected invalid settings"),
+        }
+    }
+
+    #[test]
+    fn test_from_sources_without_files_should_use_defaults() {
+        // Act
+        let settings = Settings::from_sources(&config_dir(&[]), &env(&[])).unwrap();
+
+        // Assert
+        assert_eq!(settings.profile, Profile::Dev);
+        assert_eq!(settings.server.bind_address, "127.0.0.1");
+        assert_eq!(settings.server.port, 3000);
+        assert_eq!(settings.server.shutdown_timeout_secs, 30);
+        assert_eq!(settings.database.url, "postgres://localhost/tasks");
+        assert_eq!(settings.database.max_connections, 10);
+        assert!(settings.database.migrate_on_startup);
+        assert_eq!(settings.jwt.secret, "secret");
+        assert_eq!(settings.jwt.access_token_ttl_secs, 900);
+        assert_eq!(settings.cors.allowed_origins, vec!["http://localhost:5173"]);
+        assert_eq!(settings.storage.backend, StorageBackendKind::Local);
+        assert_eq!(settings.storage.local.max_upload_bytes, 5 * 1024 * 1024);
+        assert_eq!(settings.default_avatar, None);
+        assert_eq!(settings.notifier.backend, NotifierBackendKind::File);
+        assert_eq!(settings.log.level, "info");
+        assert_eq!(settings.log.format, LogFormat::Json);
+        assert_eq!(settings.admin_token, None);
+    }
+
+    #[test]
+    fn test_from_sources_should_layer_profile_file_and_environment() {
+        // Arrange
+        let dir = config_dir(&[
+            (
+                "default.toml",
+                "[server]\nport = 4000\nbind_address = \"0.0.0.0\"\n[database]\nmax_connections = 20\n",
+            ),
+            ("test.toml", "[server]\nport = 5000\n"),
+            ("prod.toml", "[server]\nport = 6000\n"),
+        ]);
+        let env = env(&[
+            ("APP_PROFILE", "test"),
+            ("DATABASE_MAX_CONNECTIONS", "30"),
+            ("DATABASE_MIGRATE_ON_STARTUP", "false"),
+            ("S3_PATH_STYLE", "false"),
+            ("LOG_FORMAT", "pretty"),
+            (
+                "CORS_ALLOWED_ORIGINS",
+                "https://a.example, https://b.example",
+            ),
+        ]);
+
+        // Act
+        let settings = Settings::from_sources(&dir, &env).unwrap();
+
+        // Assert
+        assert_eq!(settings.profile, Profile::Test);
+        assert_eq!(settings.server.bind_address, "0.0.0.0");
+        assert_eq!(settings.server.port, 5000);
+        assert_eq!(settings.database.max_connections, 30);
+        assert!(!settings.database.migrate_on_startup);
+        assert!(!settings.storage.s3.path_style);
+        assert_eq!(settings.log.format, LogFormat::Pretty);
+        assert_eq!(
+            settings.cors.allowed_origins,
+            vec!["https://a.example", "https://b.example"]
+        );
+    }
+
+    #[test]
+    fn test_from_sources_without_required_values_should_report_each() {
+        // Arrange
+        let env = HashMap::from([
+            ("STORAGE_BACKEND".to_string(), "s3".to_string()),
+            ("LOG_LEVEL".to_string(), "info,[".to_string()),
+        ]);
+
+        // Act
+        let result = Settings::from_sources(&config_dir(&[]), &env);
+
+        // Assert
+        assert_eq!(
+            problems(result),
+            vec![
+                "DATABASE_URL must be set",
+                "JWT_SECRET must be set",
+                "LOG_LEVEL `info,[` is not a valid filter",
+                "S3_ENDPOINT must be set for the s3 backend",
+                "S3_ACCESS_KEY_ID must be set for the s3 backend",
+                "S3_SECRET_ACCESS_KEY must be set for the s3 backend",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_sources_with_prod_profile_should_require_strong_secrets() {
+        // Act
+        let result = Settings::from_sources(&config_dir(&[]), &env(&[("APP_PROFILE", "prod")]));
+
+        // Assert
+        assert_eq!(
+            problems(result),
+            vec![
+                "JWT_SECRET must be at least 32 bytes in prod",
+                "LOCAL_STORAGE_SECRET must be set in prod",
+                "NOTIFIER_BACKEND must not be `file` in prod",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_sources_with_prod_profile_should_accept_disabled_notifier() {
+        // Arrange
+        let env = env(&[
+            ("APP_PROFILE", "prod"),
+            ("JWT_SECRET", &"s".repeat(32)),
+            ("LOCAL_STORAGE_SECRET", "storage-secret"),
+            ("NOTIFIER_BACKEND", "disabled"),
+        ]);
+
+        // Act
+        let settings = Settings::from_sources(&config_dir(&[]), &env).unwrap();
+
+        // Assert
+        assert_eq!(settings.notifier.backend, NotifierBackendKind::Disabled);
+    }
+
+    #[test]
+    fn test_from_sources_with_malformed_values_should_fail_to_load() {
+        for vars in [
+            [("APP_PROFILE", "staging")],
+            [("PORT", "not-a-port")],
+            [("STORAGE_BACKEND", "ftp")],
+            [("NOTIFIER_BACKEND", "smtp")],
+        ] {
+            // Act
+            let result = Settings::from_sources(&config_dir(&[]), &env(&vars));
+
+            // Assert
+            assert!(
+                matches!(result, Err(SettingsError::Load(_))),
+                "{:?} should not load",
+                vars
+            );
+        }
+    }
+}
@@ -8,27 +8,81 @@ use actix_web::{
 use sqlx::PgPool;
 
 use crate::{
-    app::use_cases::{
-        create_user_session_use_case::CreateUserSessionUseCase,
-        get_signed_url_use_case::GetSignedUrlUseCase,
-        get_user_profile_use_case::GetUserProfileUseCase,
-        list_all_tasks_use_case::{self, ListAllTasksUseCase},
-        register_user_use_case::RegisterUserUseCase,
-        save_task_use_case::SaveTaskUseCase,
-        update_task_status_use_case::UpdateTaskStatusUseCase,
+    app::{
+        repositories::session_repository::SessionRepository,
+        storage::object_storage::ObjectStorage,
+        use_cases::{
+            change_password_use_case::ChangePasswordUseCase,
+            confirm_password_reset_use_case::ConfirmPasswordResetUseCase,
+            create_user_session_use_case::CreateUserSessionUseCase,
+            delete_account_use_case::DeleteAccountUseCase, delete_task_use_case::DeleteTaskUseCase,
+            export_user_data_use_case::ExportUserDataUseCase,
+            get_signed_url_use_case::GetSignedUrlUseCase,
+            get_user_profile_use_case::GetUserProfileUseCase,
+            list_all_tasks_use_case::ListAllTasksUseCase,
+            list_trashed_tasks_use_case::ListTrashedTasksUseCase,
+            purge_task_use_case::PurgeTaskUseCase, refresh_session_use_case::RefreshSessionUseCase,
+            register_user_use_case::RegisterUserUseCase,
+            request_password_reset_use_case::RequestPasswordResetUseCase,
+            restore_task_use_case::RestoreTaskUseCase,
+            revoke_all_sessions_use_case::RevokeAllSessionsUseCase,
+            revoke_session_use_case::RevokeSessionUseCase, save_task_use_case::SaveTaskUseCase,
+            update_avatar_use_case::UpdateAvatarUseCase,
+            update_task_status_use_case::UpdateTaskStatusUseCase,
+            update_task_use_case::UpdateTaskUseCase,
+            update_user_profile_use_case::UpdateUserProfileUseCase,
+        },
     },
     infra::{
         db::sqlx_repository::SqlxRepository,
+        health::ReadinessProbe,
         http::{
+            change_password_controller::change_password_controller,
+            confirm_avatar_upload_controller::confirm_avatar_upload_controller,
+            confirm_password_reset_controller::confirm_password_reset_controller,
             create_user_session_controller::create_user_session_controller,
+            delete_account_controller::delete_account_controller,
+            delete_task_controller::delete_task_controller,
+            download_stored_object_controller::download_stored_object_controller,
+            export_user_data_controller::export_user_data_controller,
             get_user_profile_controller::get_user_profile_controller,
+            health_controller::{liveness_controller, readiness_controller},
             list_all_user_tasks_controller::list_all_user_tasks_controller,
+            list_trashed_tasks_controller::list_trashed_tasks_controller,
+            log_level_controller::{get_log_level_controller, set_log_level_controller},
+            metrics_controller::metrics_controller,
+            problem_details::{json_error_handler, path_error_handler, query_error_handler},
+            purge_task_controller::purge_task_controller,
+            refresh_session_controller::refresh_session_controller,
             register_user_controller::register_user_controller,
+            request_avatar_upload_controller::request_avatar_upload_controller,
+            request_password_reset_controller::request_password_reset_controller,
+            reset_avatar_controller::reset_avatar_controller,
+            restore_task_controller::restore_task_controller,
+            revoke_all_sessions_controller::revoke_all_sessions_controller,
+            revoke_session_controller::revoke_session_controller,
             save_task_controller::save_task_controller,
+            update_task_controller::update_task_controller,
             update_task_status_controller::update_task_status_controller,
+            update_user_profile_controller::update_user_profile_controller,
+            upload_stored_object_controller::upload_stored_object_controller,
+        },
+        logging::LogLevelHandle,
+        metrics::Metrics,
+        middlewares::{
+            check_admin_token::{AdminToken, check_admin_token},
+            check_request_jwt::check_request_jwt,
+            record_request_metrics::record_request_metrics,
+            trace_request::{REQUEST_ID_HEADER, trace_request},
+        },
+        notifiers::configuration::get_notifier,
+        storage::{
+            cached_object_storage::CachedObjectStorage,
+            configuration::{StorageBackend, get_storage_backend},
+            instrumented_object_storage::InstrumentedObjectStorage,
         },
-        middlewares::check_request_jwt::check_request_jwt,
     },
+    settings::Settings,
 };
 
 pub struct AppState {
@@ -46,53 +100,253 @@ async fn health_check() -> impl Responder {
     HttpResponse::Ok().body("ok")
 }
 
-pub fn run(listener: TcpListener) -> Result<Server, std::io::Error> {
-    let register_user_use_case =
-        web::Data::new(RegisterUserUseCase::new(Arc::new(SqlxRepository {})));
-    let get_signed_url_use_case = web::Data::new(GetSignedUrlUseCase::new());
-    let create_user_session_use_case =
-        web::Data::new(CreateUserSessionUseCase::new(Arc::new(SqlxRepository {})));
-    let get_user_profile_use_case =
-        web::Data::new(GetUserProfileUseCase::new(Arc::new(SqlxRepository {})));
-    let save_task_use_case = web::Data::new(SaveTaskUseCase::new(Arc::new(SqlxRepository {})));
-    let list_all_tasks_use_case =
-        web::Data::new(ListAllTasksUseCase::new(Arc::new(SqlxRepository {})));
-    let update_task_status_use_case =
-        web::Data::new(UpdateTaskStatusUseCase::new(Arc::new(SqlxRepository {})));
+pub fn run(
+    listener: TcpListener,
+    pool: PgPool,
+    settings: Settings,
+    log_level: LogLevelHandle,
+) -> Result<Server, std::io::Error> {
+    let app_state = web::Data::new(AppState::new(pool.clone()));
+    let jwt_settings = web::Data::new(settings.jwt.clone());
+    let metrics = Arc::new(Metrics::new());
+    let storage_backend = get_storage_backend(&settings.storage);
+    let object_storage: Arc<dyn ObjectStorage + Send + Sync> =
+        Arc::new(CachedObjectStorage::new(Arc::new(
+            InstrumentedObjectStorage::new(storage_backend.object_storage(), metrics.clone()),
+        )));
+    let metrics = web::Data::from(metrics);
+    // Probes talk to the backend directly, past the URL cache and metrics.
+    let readiness_probe = web::Data::new(ReadinessProbe::new(
+        pool.clone(),
+        storage_backend.object_storage(),
+    ));
+    let register_user_use_case = web::Data::new(RegisterUserUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        object_storage.clone(),
+    ));
+    // Only the local backend needs this server to answer its URLs.
+    let local_storage = match &storage_backend {
+        StorageBackend::Local(storage) => Some(web::Data::from(storage.clone())),
+        StorageBackend::S3(_) => None,
+    };
+    let local_storage_max_upload_bytes = settings.storage.local.max_upload_bytes;
+    let get_signed_url_use_case = web::Data::new(GetSignedUrlUseCase::new(object_storage.clone()));
+    let session_repository: Arc<dyn SessionRepository + Send + Sync> =
+        Arc::new(SqlxRepository::new(pool.clone()));
+    let create_user_session_use_case = web::Data::new(CreateUserSessionUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        session_repository.clone(),
+        settings.jwt.clone(),
+    ));
+    let refresh_session_use_case = web::Data::new(RefreshSessionUseCase::new(
+        session_repository.clone(),
+        settings.jwt.clone(),
+    ));
+    let revoke_session_use_case =
+        web::Data::new(RevokeSessionUseCase::new(session_repository.clone()));
+    let revoke_all_sessions_use_case =
+        web::Data::new(RevokeAllSessionsUseCase::new(session_repository.clone()));
+    let request_password_reset_use_case = web::Data::new(RequestPasswordResetUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        Arc::new(SqlxRepository::new(pool.clone())),
+        get_notifier(&settings.notifier),
+        settings.jwt.password_reset_token_ttl(),
+    ));
+    let confirm_password_reset_use_case = web::Data::new(ConfirmPasswordResetUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+    ));
+    let update_user_profile_use_case = web::Data::new(UpdateUserProfileUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let change_password_use_case = web::Data::new(ChangePasswordUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        session_repository.clone(),
+    ));
+    let delete_account_use_case = web::Data::new(DeleteAccountUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        object_storage.clone(),
+    ));
+    let update_avatar_use_case = web::Data::new(UpdateAvatarUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        object_storage.clone(),
+    ));
+    let export_user_data_use_case = web::Data::new(ExportUserDataUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        Arc::new(SqlxRepository::new(pool.clone())),
+    ));
+    // The JWT middleware looks sessions up directly to reject revoked ones.
+    let session_repository = web::Data::from(session_repository);
+    let get_user_profile_use_case = web::Data::new(GetUserProfileUseCase::new(
+        Arc::new(SqlxRepository::new(pool.clone())),
+        object_storage.clone(),
+    ));
+    let save_task_use_case = web::Data::new(SaveTaskUseCase::new(Arc::new(SqlxRepository::new(
+        pool.clone(),
+    ))));
+    let list_all_tasks_use_case = web::Data::new(ListAllTasksUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let update_task_status_use_case = web::Data::new(UpdateTaskStatusUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let update_task_use_case = web::Data::new(UpdateTaskUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let delete_task_use_case = web::Data::new(DeleteTaskUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let list_trashed_tasks_use_case = web::Data::new(ListTrashedTasksUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let restore_task_use_case = web::Data::new(RestoreTaskUseCase::new(Arc::new(
+        SqlxRepository::new(pool.clone()),
+    )));
+    let purge_task_use_case = web::Data::new(PurgeTaskUseCase::new(Arc::new(SqlxRepository::new(
+        pool.clone(),
+    ))));
+
+    let cors_settings = settings.cors.clone();
+    // The admin endpoints only exist when a token is configured.
+    let admin = settings.admin_token.clone().map(|token| {
+        (
+            web::Data::new(AdminToken::new(token)),
+            web::Data::new(log_level),
+        )
+    });
 
     let server = HttpServer::new(move || {
-        let cors = Cors::default()
-            .allowed_origin("http://localhost:5173")
-            .allowed_methods(vec!["GET", "POST"])
+        let cors = cors_settings
+            .allowed_origins
+            .iter()
+            .fold(Cors::default(), |cors, origin| cors.allowed_origin(origin))
+            .allowed_methods(vec!["GET", "POST", "PUT", "PATCH", "DELETE"])
             .allowed_headers(vec![http::header::AUTHORIZATION, http::header::ACCEPT])
             .allowed_header(http::header::CONTENT_TYPE)
-            .max_age(3600);
+            .expose_headers(vec![http::header::WWW_AUTHENTICATE, REQUEST_ID_HEADER])
+            .max_age(cors_settings.max_age_secs);
         App::new()
             .wrap(cors)
+            .wrap(from_fn(record_request_metrics))
+            .wrap(from_fn(trace_request))
             .service(health_check)
+            .service(liveness_controller)
+            .service(readiness_controller)
             .service(register_user_controller)
             .service(create_user_session_controller)
+            .service(refresh_session_controller)
+            .service(revoke_session_controller)
+            .service(request_password_reset_controller)
+            .service(confirm_password_reset_controller)
+            .configure(|cfg| {
+                if let Some(local_storage) = &local_storage {
+                    // The upload limit only applies to the storage routes.
+                    cfg.service(
+                        web::scope("/storage")
+                            .app_data(local_storage.clone())
+                            .app_data(web::PayloadConfig::new(local_storage_max_upload_bytes))
+                            .service(upload_stored_object_controller)
+                            .service(download_stored_object_controller),
+                    );
+                }
+                if let Some((admin_token, log_level)) = &admin {
+                    cfg.app_data(admin_token.clone())
+                        .app_data(log_level.clone())
+                        .service(
+                            web::scope("/admin")
+                                .wrap(from_fn(check_admin_token))
+                                .service(metrics_controller)
+                                .service(get_log_level_controller)
+                                .service(set_log_level_controller),
+                        );
+                }
+            })
             .service(
                 web::scope("/users")
                     .wrap(from_fn(check_request_jwt))
-                    .service(get_user_profile_controller),
+                    .service(get_user_profile_controller)
+                    .service(update_user_profile_controller)
+                    .service(change_password_controller)
+                    .service(delete_account_controller)
+                    .service(export_user_data_controller)
+                    .service(request_avatar_upload_controller)
+                    .service(confirm_avatar_upload_controller)
+                    .service(reset_avatar_controller)
+                    .service(revoke_all_sessions_controller),
             )
             .service(
                 web::scope("/tasks")
                     .wrap(from_fn(check_request_jwt))
                     .service(save_task_controller)
                     .service(list_all_user_tasks_controller)
-                    .service(update_task_status_controller),
+                    .service(update_task_status_controller)
+                    .service(update_task_controller)
+                    .service(list_trashed_tasks_controller)
+                    .service(restore_task_controller)
+                    .service(purge_task_controller)
+                    .service(delete_task_controller),
             )
+            .app_data(web::JsonConfig::default().error_handler(json_error_handler))
+            .app_data(web::QueryConfig::default().error_handler(query_error_handler))
+            .app_data(web::PathConfig::default().error_handler(path_error_handler))
+            .app_data(app_state.clone())
+            .app_data(metrics.clone())
+            .app_data(readiness_probe.clone())
+            .app_data(jwt_settings.clone())
             .app_data(register_user_use_case.clone())
+            .app_data(session_repository.clone())
             .app_data(create_user_session_use_case.clone())
+            .app_data(refresh_session_use_case.clone())
+            .app_data(revoke_session_use_case.clone())
+            .app_data(revoke_all_sessions_use_case.clone())
+            .app_data(request_password_reset_use_case.clone())
+            .app_data(confirm_password_reset_use_case.clone())
+            .app_data(update_user_profile_use_case.clone())
+            .app_data(change_password_use_case.clone())
+            .app_data(delete_account_use_case.clone())
+            .app_data(export_user_data_use_case.clone())
+            .app_data(update_avatar_use_case.clone())
             .app_data(get_signed_url_use_case.clone())
             .app_data(get_user_profile_use_case.clone())
             .app_data(save_task_use_case.clone())
             .app_data(list_all_tasks_use_case.clone())
             .app_data(update_task_status_use_case.clone())
+            .app_data(update_task_use_case.clone())
+            .app_data(delete_task_use_case.clone())
+            .app_data(list_trashed_tasks_use_case.clone())
+            .app_data(restore_task_use_case.clone())
+            .app_data(purge_task_use_case.clone())
     })
+    .shutdown_timeout(settings.server.shutdown_timeout_secs)
+    .shutdown_signal(shutdown_signal())
     .listen(listener)?
     .run();
     Ok(server)
 }
+
+/// Resolves on `SIGTERM` or `SIGINT` (Ctrl+C elsewhere). Both start a
+/// graceful shutdown: new connections are refused and in-flight requests get
+/// `shutdown_timeout_secs` to finish. Actix alone would stop at once on
+/// `SIGINT`.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    let signal = {
+        use actix_web::rt::signal::unix::{SignalKind, signal};
+        use futures_util::future::{Either, select};
+        use std::pin::pin;
+
+        let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+        let mut interrupt = signal(SignalKind::interrupt()).expect("Failed to listen for SIGINT");
+        match select(pin!(terminate.recv()), pin!(interrupt.recv())).await {
+            Either::Left(_) => "SIGTERM",
+            Either::Right(_) => "SIGINT",
+        }
+    };
+    #[cfg(not(unix))]
+    let signal = {
+        actix_web::rt::signal::ctrl_c()
+            .await
+            .expect("Failed to listen for Ctrl+C");
+        "Ctrl+C"
+    };
+    tracing::info!(signal, "shutdown requested, draining in-flight requests");
+}
@@ -8,9 +8,11 @@ import {
 } from "./ui/carousel";
 import { useListUserTasks } from "@/http/use-list-user-tasks";
 import { DayCard } from "./dayCard";
+import { Button } from "./ui/button";
 
 export const Calendar = () => {
-  const { data } = useListUserTasks();
+  const { data, hasNextPage, fetchNextPage, isFetchingNextPage } =
+    useListUserTasks();
 
   const currentYear = dayjs().year();
   const currentMonthIndex = dayjs().month();
@@ -87,6 +89,17 @@ export const Calendar = () => {
         <CarouselPrevious className="hidden sm:flex" />
         <CarouselNext className="hidden sm:flex" />
       </Carousel>
+      {hasNextPage && (
+        <div className="flex justify-center mt-4">
+          <Button
+            variant="outline"
+            disabled={isFetchingNextPage}
+            onClick={() => fetchNextPage()}
+          >
+            {isFetchingNextPage ? "Carregando..." : "Carregar mais tarefas"}
+          </Button>
+        </div>
+      )}
     </div>
   );
 };
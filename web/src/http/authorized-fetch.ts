@@ -0,0 +1,65 @@
+import Cookies from "js-cookie";
+import type { CreateSessionResponse } from "./types/create-session-response";
+
+const cookieOptions = {
+  expires: 30,
+  path: "/",
+  secure: true,
+  sameSite: "strict",
+} as const;
+
+export function storeSession({ access_token, refresh_token }: CreateSessionResponse) {
+  Cookies.set("token", access_token, cookieOptions);
+  Cookies.set("refresh_token", refresh_token, cookieOptions);
+}
+
+export function clearSession() {
+  Cookies.remove("token", { path: "/" });
+  Cookies.remove("refresh_token", { path: "/" });
+}
+
+async function refreshSession(): Promise<boolean> {
+  const refreshToken = Cookies.get("refresh_token");
+  if (!refreshToken) {
+    return false;
+  }
+
+  const response = await fetch("http://localhost:3000/sessions/refresh", {
+    method: "POST",
+    headers: {
+      "Content-Type": "application/json",
+    },
+    body: JSON.stringify({ refresh_token: refreshToken }),
+  });
+
+  if (!response.ok) {
+    clearSession();
+    return false;
+  }
+
+  storeSession(await response.json());
+  return true;
+}
+
+// Sends the access token and, when it has expired, refreshes the session once
+// and retries the request with the new token.
+export async function authorizedFetch(
+  input: string,
+  init: RequestInit = {}
+): Promise<Response> {
+  const send = () =>
+    fetch(input, {
+      ...init,
+      headers: {
+        ...init.headers,
+        authorization: `Bearer ${Cookies.get("token")}`,
+      },
+    });
+
+  const response = await send();
+  if (response.status === 401 && (await refreshSession())) {
+    return send();
+  }
+
+  return response;
+}
@@ -0,0 +1,37 @@
+import type { InvalidParam, ProblemDetails } from "./types/problem-details";
+
+export class ProblemError extends Error {
+  readonly status: number;
+  readonly code: string;
+  readonly invalidParams: InvalidParam[];
+
+  constructor(problem: ProblemDetails) {
+    super(problem.detail);
+    this.name = "ProblemError";
+    this.status = problem.status;
+    this.code = problem.code;
+    this.invalidParams = problem["invalid-params"] ?? [];
+  }
+}
+
+export async function ensureOk(response: Response): Promise<Response> {
+  if (response.ok) {
+    return response;
+  }
+
+  const isProblem = response.headers
+    .get("Content-Type")
+    ?.includes("application/problem+json");
+
+  if (isProblem) {
+    throw new ProblemError(await response.json());
+  }
+
+  throw new ProblemError({
+    type: "about:blank",
+    title: response.statusText,
+    status: response.status,
+    detail: response.statusText,
+    code: "unknown_error",
+  });
+}
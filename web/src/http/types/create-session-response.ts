@@ -1,3 +1,6 @@
 export type CreateSessionResponse = {
-  token: string;
+  access_token: string;
+  refresh_token: string;
+  token_type: "Bearer";
+  expires_in: number;
 };
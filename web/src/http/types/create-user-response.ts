@@ -1,3 +1,7 @@
 export type CreateUserResponse = {
-  url: string;
+  id: string;
+  avatar_upload: {
+    url: string;
+    file_key: string;
+  } | null;
 };
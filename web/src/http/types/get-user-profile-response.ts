@@ -1,5 +1,6 @@
 export type GetUserProfileResponse = {
   avatar_url: string;
+  thumbnail_url: string | null;
   username: string;
   email: string;
 };
@@ -7,4 +7,7 @@ export type TaskResponse = {
   created_at: string;
 };
 
-export type ListUserTasksResponse = { tasks: Array<TaskResponse> };
+export type ListUserTasksResponse = {
+  tasks: Array<TaskResponse>;
+  next_cursor: string | null;
+};
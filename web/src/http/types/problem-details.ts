@@ -0,0 +1,13 @@
+export type ProblemDetails = {
+  type: string;
+  title: string;
+  status: number;
+  detail: string;
+  code: string;
+  "invalid-params"?: InvalidParam[];
+};
+
+export type InvalidParam = {
+  name: string;
+  reason: string;
+};
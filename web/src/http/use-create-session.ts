@@ -1,7 +1,8 @@
 import { useMutation, useQueryClient } from "@tanstack/react-query";
-import Cookies from "js-cookie";
 import type { CreateSessionRequest } from "./types/create-session-request";
 import type { CreateSessionResponse } from "./types/create-session-response";
+import { ensureOk } from "./problem-error";
+import { storeSession } from "./authorized-fetch";
 
 export function useCreateSession() {
   const queryClient = useQueryClient();
@@ -15,20 +16,15 @@ export function useCreateSession() {
         body: JSON.stringify({ ...data }),
       });
 
+      await ensureOk(response);
+
       const token = await response.json();
 
       return token;
     },
     async onSuccess(data: CreateSessionResponse, variables, context) {
-      const { token } = data;
-
-      if (token) {
-        Cookies.set("token", token, {
-          expires: 7,
-          path: "/",
-          secure: true,
-          sameSite: "strict",
-        });
+      if (data.access_token) {
+        storeSession(data);
       }
     },
     onError(error) {
@@ -1,5 +1,6 @@
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import Cookies from "js-cookie";
+import { authorizedFetch } from "./authorized-fetch";
 import type { CreateTaskRequest } from "./types/create-task-request";
 
 export function useCreateTask() {
@@ -11,11 +12,10 @@ export function useCreateTask() {
         console.log("user");
         return;
       }
-      await fetch("http://localhost:3000/tasks/save", {
+      await authorizedFetch("http://localhost:3000/tasks/save", {
         method: "POST",
         headers: {
           "Content-Type": "application/json",
-          authorization: token,
         },
 
         body: JSON.stringify({ ...data }),
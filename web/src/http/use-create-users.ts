@@ -1,35 +1,66 @@
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import type { CreateUserRequest } from "./types/create-user-request";
 import type { CreateUserResponse } from "./types/create-user-response";
+import { ensureOk } from "./problem-error";
+import { authorizedFetch, storeSession } from "./authorized-fetch";
 
 export function useCreateUser() {
   const queryClient = useQueryClient();
   return useMutation({
     mutationFn: async (data: CreateUserRequest) => {
-      console.log("", data.file_key?.type);
       const response = await fetch("http://localhost:3000/users", {
         method: "POST",
         headers: {
           "Content-Type": "application/json",
         },
-        body: JSON.stringify({ ...data, file_key: data.file_key?.name }),
+        body: JSON.stringify({
+          email: data.email,
+          username: data.username,
+          password: data.password,
+          mime_type: data.file_key?.type,
+        }),
       });
 
-      const result = await response.json();
-
-      // Make the
-      return result;
+      await ensureOk(response);
+      return response.json();
     },
-    async onSuccess(data: CreateUserResponse, variables, context) {
-      console.log("API url:", data.url);
-      const response = await fetch(data.url, {
+    async onSuccess(data: CreateUserResponse, variables) {
+      // Without a picture the server keeps the generated avatar.
+      if (!data.avatar_upload || !variables.file_key) return;
+      const upload = await fetch(data.avatar_upload.url, {
         method: "PUT",
         headers: {
-          "Content-Type": variables.file_key?.type ?? "image/jpeg",
+          "Content-Type": variables.file_key.type,
         },
         body: variables.file_key,
       });
-      console.log("response", response);
+      await ensureOk(upload);
+
+      // The upload only becomes the avatar once confirmed by the new user.
+      const session = await fetch("http://localhost:3000/session", {
+        method: "POST",
+        headers: {
+          "Content-Type": "application/json",
+        },
+        body: JSON.stringify({
+          email: variables.email,
+          password: variables.password,
+        }),
+      });
+      await ensureOk(session);
+      storeSession(await session.json());
+
+      const confirmation = await authorizedFetch("http://localhost:3000/users/avatar", {
+        method: "PUT",
+        headers: {
+          "Content-Type": "application/json",
+        },
+        body: JSON.stringify({
+          file_key: data.avatar_upload.file_key,
+          mime_type: variables.file_key.type,
+        }),
+      });
+      await ensureOk(confirmation);
     },
   });
 }
@@ -1,5 +1,6 @@
 import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import Cookies from "js-cookie";
+import { authorizedFetch } from "./authorized-fetch";
 import type { GetUserProfileRequest } from "./types/get-user-profile-request";
 import type { GetUserProfileResponse } from "./types/get-user-profile-response";
 
@@ -14,11 +15,8 @@ export function useGetUserProfile() {
         return;
       }
 
-      const response = await fetch("http://localhost:3000/users/profile", {
+      const response = await authorizedFetch("http://localhost:3000/users/profile", {
         method: "GET",
-        headers: {
-          authorization: token,
-        },
       });
 
       const user: GetUserProfileResponse = await response.json();
@@ -1,53 +1,60 @@
-import { useQuery } from "@tanstack/react-query";
+import { useInfiniteQuery } from "@tanstack/react-query";
 import Cookies from "js-cookie";
-import type { ListUserTasksResponse } from "./types/list-user-tasks-response";
+import { authorizedFetch } from "./authorized-fetch";
+import { ensureOk } from "./problem-error";
+import type {
+  ListUserTasksResponse,
+  TaskResponse,
+} from "./types/list-user-tasks-response";
 import type { Task } from "./types/tasks";
 
-export function useListUserTasks() {
-  return useQuery({
-    queryKey: ["list-user-tasks"],
-    queryFn: async () => {
-      let token = Cookies.get("token");
-      if (!token) {
-        console.log("user");
-        return;
-      }
+const PAGE_SIZE = "100";
 
-      const response = await fetch("http://localhost:3000/tasks/list", {
-        method: "GET",
-        headers: {
-          authorization: token,
-        },
-      });
-
-      const result: ListUserTasksResponse = await response.json();
+function toTask(task: TaskResponse): Task {
+  let color: string = "";
+  if (task.status === "ToStart") {
+    color = "bg-red-500";
+  } else if (task.status === "Started") {
+    color = "bg-yellow-500";
+  } else if (task.status === "Completed") {
+    color = "bg-green-500";
+  }
 
-      const tasks: Array<Task> = result.tasks.map((tasks) => {
-        let color: string = "";
-        let task_date = new Date(tasks.task_date);
-        let created_at = new Date(tasks.created_at);
+  return {
+    id: task.id,
+    user_id: task.user_id,
+    status: task.status,
+    content: task.content,
+    color,
+    task_date: new Date(task.task_date),
+    created_at: new Date(task.created_at),
+  };
+}
 
-        if (tasks.status === "ToStart") {
-          color = "bg-red-500";
-        } else if (tasks.status === "Started") {
-          color = "bg-yellow-500";
-        } else if (tasks.status === "Completed") {
-          color = "bg-green-500";
+// Loads one page of tasks at a time; `fetchNextPage` appends the next one
+// while `hasNextPage` is true. `data` holds the tasks of every loaded page.
+export function useListUserTasks() {
+  return useInfiniteQuery({
+    queryKey: ["list-user-tasks"],
+    enabled: Boolean(Cookies.get("token")),
+    initialPageParam: null as string | null,
+    queryFn: async ({ pageParam }) => {
+      const params = new URLSearchParams({ limit: PAGE_SIZE });
+      if (pageParam) {
+        params.set("cursor", pageParam);
+      }
+      const response = await authorizedFetch(
+        `http://localhost:3000/tasks/list?${params.toString()}`,
+        {
+          method: "GET",
         }
-        console.log(task_date);
-        console.log("tasks:", tasks.task_date);
-        return {
-          id: tasks.id,
-          user_id: tasks.user_id,
-          status: tasks.status,
-          content: tasks.content,
-          color,
-          task_date,
-          created_at,
-        };
-      });
+      );
+      await ensureOk(response);
 
-      return tasks;
+      const result: ListUserTasksResponse = await response.json();
+      return result;
     },
+    getNextPageParam: (lastPage) => lastPage.next_cursor,
+    select: (data) => data.pages.flatMap((page) => page.tasks.map(toTask)),
   });
 }
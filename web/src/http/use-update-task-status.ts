@@ -1,5 +1,6 @@
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import Cookies from "js-cookie";
+import { authorizedFetch } from "./authorized-fetch";
 import type { UpdateTaskStatusRequest } from "./types/update-task-status-request";
 
 export function useUpdateTaskStatus() {
@@ -11,13 +12,12 @@ export function useUpdateTaskStatus() {
         console.log("user");
         return;
       }
-      await fetch(
+      await authorizedFetch(
         `http://localhost:3000/tasks/update/${data.task_id}/${data.action}`,
         {
           method: "PUT",
           headers: {
             "Content-Type": "application/json",
-            authorization: token,
           },
         }
       );
@@ -12,7 +12,7 @@ export const Home = () => {
       ) : (
         data && (
           <div>
-            <Header avatar_url={data.avatar_url} />
+            <Header avatar_url={data.thumbnail_url ?? data.avatar_url} />
             <Calendar />
           </div>
         )
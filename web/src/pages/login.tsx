@@ -20,6 +20,7 @@ import { zodResolver } from "@hookform/resolvers/zod";
 import { Input } from "@/components/ui/input";
 import { Link, useNavigate } from "react-router-dom";
 import { useCreateSession } from "@/http/use-create-session";
+import { ProblemError } from "@/http/problem-error";
 
 const loginUserSchema = z.object({
   email: z.email(),
@@ -40,8 +41,14 @@ export const Login = () => {
   });
 
   async function handleUserLogin({ email, password }: loginUserSchemaData) {
-    await createSession({ email, password });
-    navigate("/");
+    try {
+      await createSession({ email, password });
+      navigate("/");
+    } catch (error) {
+      const message =
+        error instanceof ProblemError ? error.message : "Falha ao entrar";
+      form.setError("root", { message });
+    }
   }
 
   return (
@@ -95,6 +102,11 @@ export const Login = () => {
                   );
                 }}
               />
+              {form.formState.errors.root && (
+                <p className="text-destructive text-sm">
+                  {form.formState.errors.root.message}
+                </p>
+              )}
               <Button type="submit" className="cursor-pointer">
                 Login
               </Button>
@@ -12,8 +12,10 @@ import {
   FormField,
   FormItem,
   FormLabel,
+  FormMessage,
 } from "@/components/ui/form";
 import { Input } from "@/components/ui/input";
+import { ProblemError } from "@/http/problem-error";
 import { useCreateUser } from "@/http/use-create-users";
 import { zodResolver } from "@hookform/resolvers/zod";
 import { useState } from "react";
@@ -21,9 +23,16 @@ import { useForm } from "react-hook-form";
 import z from "zod";
 
 const createUserSchema = z.object({
-  username: z.string().min(3, { message: "min 3 characters" }),
-  email: z.email(),
-  password: z.string().min(3, { message: "min 3 characters" }),
+  username: z
+    .string()
+    .trim()
+    .min(1, { message: "required" })
+    .max(60, { message: "max 60 characters" }),
+  email: z.email().max(255, { message: "max 255 characters" }),
+  password: z
+    .string()
+    .min(8, { message: "min 8 characters" })
+    .max(128, { message: "max 128 characters" }),
   avatar: z
     .instanceof(File, { message: "You must upload an image " })
     .refine((file) => file.size > 0, { message: "file is required" }),
@@ -45,13 +54,34 @@ export const SignUp = () => {
   const [avatarPreview, setAvatarPreview] = useState<string | null>(null);
 
   async function handleSignUpUser(user: createUserData) {
-    const result = await createUser({
-      username: user.username,
-      email: user.email,
-      password: user.password,
-      file_key: user.avatar,
-      mime_type: user.avatar.type,
-    });
+    try {
+      await createUser({
+        username: user.username,
+        email: user.email,
+        password: user.password,
+        file_key: user.avatar,
+        mime_type: user.avatar.type,
+      });
+    } catch (error) {
+      if (!(error instanceof ProblemError)) {
+        form.setError("root", { message: "Falha ao criar usuário" });
+        return;
+      }
+      if (error.code === "email_already_registered") {
+        form.setError("email", { message: error.message });
+        return;
+      }
+      for (const param of error.invalidParams) {
+        if (param.name in user) {
+          form.setError(param.name as keyof createUserData, {
+            message: param.reason,
+          });
+        }
+      }
+      if (error.invalidParams.length === 0) {
+        form.setError("root", { message: error.message });
+      }
+    }
   }
   return (
     <div className="flex items-center justify-center min-h-screen">
@@ -80,6 +110,7 @@ export const SignUp = () => {
                           <FormControl>
                             <Input {...field} placeholder="Digite seu email" />
                           </FormControl>
+                          <FormMessage />
                         </FormItem>
                       );
                     }}
@@ -97,6 +128,7 @@ export const SignUp = () => {
                               placeholder="Digite seu username"
                             />
                           </FormControl>
+                          <FormMessage />
                         </FormItem>
                       );
                     }}
@@ -115,6 +147,7 @@ export const SignUp = () => {
                               type="password"
                             />
                           </FormControl>
+                          <FormMessage />
                         </FormItem>
                       );
                     }}
@@ -132,7 +165,7 @@ export const SignUp = () => {
                         <FormControl>
                           <Input
                             type="file"
-                            accept="image/*"
+                            accept="image/jpeg,image/png,image/gif,image/webp"
                             name={field.name}
                             ref={field.ref}
                             onBlur={field.onBlur}
@@ -159,6 +192,11 @@ export const SignUp = () => {
                   )}
                 </div>
               </div>
+              {form.formState.errors.root && (
+                <p className="text-destructive text-sm">
+                  {form.formState.errors.root.message}
+                </p>
+              )}
               <Button type="submit">Criar usuário</Button>
             </form>
           </Form>